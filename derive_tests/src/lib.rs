@@ -1,3 +1,5 @@
+#![allow(dead_code)]
+
 use chrono::NaiveDateTime;
 use validify::{schema_err, schema_validation, ValidationError};
 #[allow(unused_imports)]
@@ -0,0 +1,67 @@
+use validify::{Validate, Validify};
+
+#[test]
+fn shared_rule_fails_on_named_fields() {
+    #[derive(Debug, Validate)]
+    #[validate(code_prefix = "user.", apply(length(max = 5), to = ["a", "b", "c"]))]
+    struct TestStruct {
+        a: String,
+        #[validate(email)]
+        b: String,
+        c: String,
+        d: String,
+    }
+
+    let s = TestStruct {
+        a: "short".to_string(),
+        b: "way@too.long".to_string(),
+        c: "much too long".to_string(),
+        d: "not checked at all".to_string(),
+    };
+
+    assert_eq!(s.d.len(), 18);
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].location(), "/b");
+    assert_eq!(errs[0].code(), "user.length");
+    assert_eq!(errs[1].location(), "/c");
+    assert_eq!(errs[1].code(), "user.length");
+
+    let s = TestStruct {
+        a: "a".to_string(),
+        b: "a@b.c".to_string(),
+        c: "c".to_string(),
+        d: "not checked at all".to_string(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn shared_rules_are_merged_with_field_rules() {
+    #[derive(Debug, Validify)]
+    #[validate(apply(iter(length(min = 1)), to = ["tags", "names"]))]
+    #[validate(apply(required, to = ["nick"]))]
+    struct TestStruct {
+        #[modify(trim)]
+        #[validate(length(max = 2))]
+        tags: Vec<String>,
+        names: Vec<String>,
+        nick: Option<String>,
+    }
+
+    let mut s = TestStruct {
+        tags: vec!["  ".to_string(), "a".to_string(), "b".to_string()],
+        names: vec![String::new()],
+        nick: None,
+    };
+
+    let err = s.validify().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 4);
+    assert_eq!(errs[0].location(), "/tags");
+    assert_eq!(errs[1].location(), "/tags/0");
+    assert_eq!(errs[2].location(), "/names/0");
+    assert_eq!(errs[3].location(), "/nick");
+    assert_eq!(errs[3].code(), "required");
+}
@@ -0,0 +1,80 @@
+use serde::Deserialize;
+use validify::{Validate, Validify};
+
+#[test]
+fn validates_heavily_attributed_fields() {
+    #[derive(Debug, Deserialize, Validify)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TestStruct {
+        /// The user's display name.
+        ///
+        /// Spans multiple doc lines.
+        #[serde(rename = "displayName", default)]
+        #[allow(dead_code)]
+        #[modify(trim)]
+        #[validate(length(min = 3))]
+        pub(crate) display_name: String,
+
+        #[doc = "Optional contact address"]
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        #[validate(email)]
+        pub contact_email: Option<String>,
+
+        /// Tags attached to the user
+        #[serde(default)]
+        #[validate(length(max = 1), iter(length(min = 2)))]
+        pub(self) tags: Vec<String>,
+    }
+
+    let mut s = TestStruct {
+        display_name: "  ab  ".to_string(),
+        contact_email: Some("nope".to_string()),
+        tags: vec!["a".to_string()],
+    };
+    let err = s.validify().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].location(), "/displayName");
+    assert_eq!(errs[1].location(), "/contactEmail");
+    assert_eq!(errs[2].location(), "/tags/0");
+
+    let s = TestStruct {
+        display_name: "abc".to_string(),
+        contact_email: None,
+        tags: vec![],
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn validates_fields_with_macro_fragment_types() {
+    macro_rules! define {
+        ($name:ident, $opt:ty, $list:ty) => {
+            #[derive(Debug, Validate)]
+            struct $name {
+                #[validate(email)]
+                email: $opt,
+                #[validate(iter(length(min = 2)))]
+                tags: $list,
+            }
+        };
+    }
+
+    define!(TestStruct, Option<String>, Vec<String>);
+
+    let s = TestStruct {
+        email: None,
+        tags: vec!["ab".to_string()],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        email: Some("nope".to_string()),
+        tags: vec!["a".to_string()],
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].location(), "/email");
+    assert_eq!(errs[1].location(), "/tags/0");
+}
@@ -0,0 +1,56 @@
+use validify::Validify;
+
+#[derive(Debug, Validify)]
+#[validify(builder)]
+struct Signup {
+    #[modify(trim, lowercase)]
+    #[validate(email)]
+    email: String,
+    #[validate(range(min = 18.))]
+    age: u8,
+    #[modify(trim)]
+    #[validate(length(min = 1))]
+    nickname: Option<String>,
+}
+
+#[test]
+fn builds_validified_struct() {
+    let signup = Signup::builder()
+        .email("  Hello@Example.com ")
+        .age(30)
+        .build()
+        .unwrap();
+
+    assert_eq!(signup.email, "hello@example.com");
+    assert_eq!(signup.age, 30);
+    assert_eq!(signup.nickname, None);
+
+    let signup = SignupBuilder::default()
+        .email("hello@example.com")
+        .age(18)
+        .nickname(" nick ")
+        .build()
+        .unwrap();
+    assert_eq!(signup.nickname.as_deref(), Some("nick"));
+}
+
+#[test]
+fn build_fails_on_invalid_or_missing_fields() {
+    let err = Signup::builder()
+        .email("not an email")
+        .age(10)
+        .build()
+        .unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "email");
+    assert_eq!(errs[1].code(), "range");
+
+    let err = Signup::builder().nickname("nick").build().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "required");
+    assert_eq!(errs[0].location(), "/email");
+    assert_eq!(errs[1].code(), "required");
+    assert_eq!(errs[1].location(), "/age");
+}
@@ -0,0 +1,75 @@
+use validify::{schema_err, schema_validation, Validate, ValidationError, ValidationErrors};
+
+fn expensive(_: &str) -> Result<(), ValidationError> {
+    Err(ValidationError::new_field("expensive").with_message("Too expensive".to_string()))
+}
+
+#[derive(Debug, Validate)]
+#[validate(schema_check)]
+struct Payload {
+    #[validate(length(min = 3), custom(expensive))]
+    name: String,
+    #[validate(iter(range(max = 10.)))]
+    values: Vec<i32>,
+}
+
+#[schema_validation]
+fn schema_check(payload: &Payload) -> Result<(), ValidationErrors> {
+    if payload.values.is_empty() {
+        schema_err!("empty", "No values");
+    }
+}
+
+#[test]
+fn errors_round_trip_through_postcard() {
+    let payload = Payload {
+        name: "ab".to_string(),
+        values: vec![1, 11],
+    };
+    let errors = payload.validate().unwrap_err();
+    assert_eq!(errors.errors().len(), 3);
+
+    let bytes = errors.to_bytes().unwrap();
+    let decoded = ValidationErrors::from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, errors);
+
+    let payload = Payload {
+        name: "abc".to_string(),
+        values: vec![],
+    };
+    let errors = payload.validate().unwrap_err();
+    let decoded = ValidationErrors::from_bytes(&errors.to_bytes().unwrap()).unwrap();
+    assert_eq!(decoded, errors);
+    assert_eq!(decoded.schema_errors()[0].code(), "empty");
+}
+
+#[test]
+fn rejects_invalid_bytes() {
+    assert!(ValidationErrors::from_bytes(&[0xFF, 0xFF]).is_err());
+}
+
+#[test]
+fn truncated_errors_round_trip() {
+    let mut errors = ValidationErrors::with_max_errors(1);
+    errors.add(ValidationError::new_field("a"));
+    errors.add(ValidationError::new_field("b"));
+    assert!(errors.is_truncated());
+
+    let decoded = ValidationErrors::from_bytes(&errors.to_bytes().unwrap()).unwrap();
+    assert!(decoded.is_truncated());
+    assert_eq!(decoded, errors);
+}
+
+#[test]
+fn rejects_codes_that_were_never_encoded() {
+    // A schema error with the `never_encoded` code
+    let mut bytes = vec![1, 0, 13];
+    bytes.extend_from_slice(b"never_encoded");
+    bytes.extend_from_slice(&[0, 1, b'/', 0]);
+    assert!(ValidationErrors::from_bytes(&bytes).is_err());
+
+    let mut errors = ValidationErrors::new();
+    errors.add(ValidationError::new_schema("never_encoded"));
+    assert_eq!(errors.to_bytes().unwrap(), bytes);
+    assert_eq!(ValidationErrors::from_bytes(&bytes).unwrap(), errors);
+}
@@ -0,0 +1,39 @@
+use validify::{Payload, Validify};
+
+#[test]
+fn validates_only_fields_present_under_cfg() {
+    #[derive(Debug, Validify, Payload)]
+    struct Gated {
+        #[modify(trim)]
+        #[validate(length(min = 1))]
+        always: String,
+        // Present in this build
+        #[cfg(test)]
+        #[modify(trim)]
+        #[validate(length(min = 3))]
+        enabled: String,
+        // Absent in this build, the generated code must not reference it
+        #[cfg(not(test))]
+        #[modify(trim)]
+        #[validate(length(min = 3))]
+        disabled: String,
+    }
+
+    let mut gated = Gated {
+        always: " a ".to_string(),
+        enabled: " ab ".to_string(),
+    };
+
+    let err = gated.validify().unwrap_err();
+    assert_eq!(gated.always, "a");
+    assert_eq!(gated.enabled, "ab");
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].location(), "/enabled");
+
+    let payload = GatedPayload::from(Gated {
+        always: "a".to_string(),
+        enabled: "abc".to_string(),
+    });
+    assert_eq!(payload.enabled.as_deref(), Some("abc"));
+}
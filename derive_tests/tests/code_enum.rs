@@ -0,0 +1,73 @@
+use validify::Validate;
+
+#[derive(Debug, Validate)]
+#[validate(code_enum = "SignupErrorCode")]
+struct Signup {
+    #[validate(email)]
+    email: String,
+    #[validate(length(min = 3, code = "username_too_short"))]
+    username: String,
+    #[validate(range(max = 130.))]
+    age: u8,
+    #[validate(iter(one_of(["admin", "user"])))]
+    roles: Vec<String>,
+    #[validate(required)]
+    terms: Option<bool>,
+}
+
+#[test]
+fn matches_errors_on_generated_code_enum() {
+    let signup = Signup {
+        email: "not an email".to_string(),
+        username: "ab".to_string(),
+        age: 200,
+        roles: vec!["guest".to_string()],
+        terms: None,
+    };
+
+    let errors = signup.validate().unwrap_err();
+    assert_eq!(errors.errors().len(), 5);
+
+    let mut matched = vec![];
+
+    for error in errors.errors() {
+        let code = SignupErrorCode::try_from(error.code().as_str()).unwrap();
+        let field = match code {
+            SignupErrorCode::Email => "email",
+            SignupErrorCode::UsernameTooShort => "username",
+            SignupErrorCode::Range => "age",
+            SignupErrorCode::OneOf => "roles",
+            SignupErrorCode::Required => "terms",
+        };
+        assert_eq!(error.field_name(), Some(field));
+        assert_eq!(code.as_str(), error.code());
+        matched.push(code);
+    }
+
+    assert_eq!(
+        matched,
+        vec![
+            SignupErrorCode::Email,
+            SignupErrorCode::UsernameTooShort,
+            SignupErrorCode::Range,
+            SignupErrorCode::OneOf,
+            SignupErrorCode::Required,
+        ]
+    );
+}
+
+#[test]
+fn code_enum_is_serde_compatible() {
+    assert_eq!(
+        serde_json::to_value(SignupErrorCode::UsernameTooShort).unwrap(),
+        "username_too_short"
+    );
+    assert_eq!(
+        serde_json::from_str::<SignupErrorCode>("\"one_of\"").unwrap(),
+        SignupErrorCode::OneOf
+    );
+    assert_eq!(
+        SignupErrorCode::try_from("unknown"),
+        Err("unknown".to_string())
+    );
+}
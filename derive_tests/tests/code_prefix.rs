@@ -0,0 +1,54 @@
+use validify::{Validate, ValidationError};
+
+fn reserved(_: &str) -> Result<(), ValidationError> {
+    Err(ValidationError::new_field("reserved"))
+}
+
+#[test]
+fn prefixes_all_generated_codes() {
+    #[derive(Debug, Validate)]
+    #[validate(code_prefix = "user.")]
+    struct User {
+        #[validate(email, length(max = 5))]
+        email: String,
+        #[validate(length(min = 3, code = "name_too_short"))]
+        name: String,
+        #[validate(iter(range(max = 10.)))]
+        scores: Vec<u8>,
+        #[validate(length(min = 8, code = "shared.password", no_code_prefix))]
+        password: String,
+        #[validate(custom(function = reserved, code = "taken"))]
+        handle: String,
+        #[validate(custom(function = reserved, code = "shared.taken", no_code_prefix))]
+        alias: String,
+        #[validate(custom(reserved))]
+        nick: String,
+    }
+
+    let err = User {
+        email: "not an email".to_string(),
+        name: "a".to_string(),
+        scores: vec![11],
+        password: "short".to_string(),
+        handle: String::new(),
+        alias: String::new(),
+        nick: String::new(),
+    }
+    .validate()
+    .unwrap_err();
+
+    let codes: Vec<_> = err.errors().iter().map(|err| err.code()).collect();
+    assert_eq!(
+        codes,
+        [
+            "user.email",
+            "user.length",
+            "user.name_too_short",
+            "user.range",
+            "shared.password",
+            "user.taken",
+            "shared.taken",
+            "reserved"
+        ]
+    );
+}
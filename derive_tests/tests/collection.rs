@@ -0,0 +1,87 @@
+use validify::{validate_collection, validate_many, Validate};
+
+#[derive(Debug, Validate)]
+struct Item {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(range(max = 10.))]
+    amount: usize,
+}
+
+#[test]
+fn validates_collection() {
+    let items = vec![
+        Item {
+            name: "first".to_string(),
+            amount: 1,
+        },
+        Item {
+            name: "second".to_string(),
+            amount: 2,
+        },
+    ];
+
+    assert!(validate_collection(&items).is_ok());
+    assert!(validate_collection::<Item>(&[]).is_ok());
+}
+
+#[test]
+fn validates_collection_with_indexed_errors() {
+    let items = vec![
+        Item {
+            name: String::new(),
+            amount: 1,
+        },
+        Item {
+            name: "second".to_string(),
+            amount: 2,
+        },
+        Item {
+            name: "third".to_string(),
+            amount: 11,
+        },
+    ];
+
+    let res = validate_collection(&items);
+    assert!(res.is_err());
+    let errs = res.unwrap_err();
+    assert_eq!(errs.errors().len(), 2);
+    assert_eq!(errs.errors()[0].code(), "length");
+    assert_eq!(errs.errors()[0].location(), "/0/name");
+    assert_eq!(errs.errors()[1].code(), "range");
+    assert_eq!(errs.errors()[1].location(), "/2/amount");
+}
+
+#[test]
+fn validates_many_with_per_item_results() {
+    let items = vec![
+        Item {
+            name: String::new(),
+            amount: 11,
+        },
+        Item {
+            name: "second".to_string(),
+            amount: 2,
+        },
+        Item {
+            name: "third".to_string(),
+            amount: 11,
+        },
+    ];
+
+    let failed = validate_many(&items);
+    assert_eq!(failed.len(), 2);
+
+    let (i, errs) = &failed[0];
+    assert_eq!(*i, 0);
+    assert_eq!(errs.errors().len(), 2);
+    assert_eq!(errs.errors()[0].location(), "/name");
+    assert_eq!(errs.errors()[1].location(), "/amount");
+
+    let (i, errs) = &failed[1];
+    assert_eq!(*i, 2);
+    assert_eq!(errs.errors().len(), 1);
+    assert_eq!(errs.errors()[0].code(), "range");
+
+    assert!(validate_many(&items[1..2]).is_empty());
+}
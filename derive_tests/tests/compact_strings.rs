@@ -0,0 +1,112 @@
+use compact_str::CompactString;
+use smol_str::SmolStr;
+use validify::Validate;
+
+#[test]
+fn validates_smol_str() {
+    #[derive(Debug, Validate)]
+    struct Contact {
+        #[validate(email, length(max = 16))]
+        email: SmolStr,
+        #[validate(length(min = 2), contains("-"))]
+        handle: Option<SmolStr>,
+        #[validate(iter(length(min = 1)))]
+        tags: Vec<SmolStr>,
+    }
+
+    let contact = Contact {
+        email: SmolStr::new("me@example.com"),
+        handle: Some(SmolStr::new("me-1")),
+        tags: vec![SmolStr::new("a")],
+    };
+    assert!(contact.validate().is_ok());
+
+    let contact = Contact {
+        email: SmolStr::new("very.long.address@example.com"),
+        handle: Some(SmolStr::new("m")),
+        tags: vec![SmolStr::new("a"), SmolStr::default()],
+    };
+    let err = contact.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 4);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].location(), "/email");
+    assert_eq!(errs[1].code(), "length");
+    assert_eq!(errs[1].location(), "/handle");
+    assert_eq!(errs[2].code(), "contains");
+    assert_eq!(errs[3].location(), "/tags/1");
+
+    let contact = Contact {
+        email: SmolStr::new("not an email"),
+        handle: None,
+        tags: vec![],
+    };
+    let err = contact.validate().unwrap_err();
+    assert_eq!(err.field_errors()[0].code(), "email");
+}
+
+#[test]
+fn validates_compact_string() {
+    #[derive(Debug, Validate)]
+    struct Contact {
+        #[validate(email, length(max = 16))]
+        email: CompactString,
+        #[validate(url, one_of(["https://example.com"]))]
+        site: Option<CompactString>,
+    }
+
+    let contact = Contact {
+        email: CompactString::new("me@example.com"),
+        site: Some(CompactString::new("https://example.com")),
+    };
+    assert!(contact.validate().is_ok());
+
+    let contact = Contact {
+        email: CompactString::new("not an email"),
+        site: Some(CompactString::new("https://example.org")),
+    };
+    let err = contact.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "email");
+    assert_eq!(errs[1].code(), "one_of");
+    assert_eq!(errs[1].location(), "/site");
+}
+
+#[test]
+fn validates_any_as_ref_str() {
+    struct Name(String);
+
+    impl AsRef<str> for Name {
+        fn as_ref(&self) -> &str {
+            &self.0
+        }
+    }
+
+    #[derive(Validate)]
+    struct Person {
+        #[validate(length(min = 2, max = 4), contains("ö"))]
+        name: Name,
+        #[validate(iter(length(max = 3)))]
+        aliases: Vec<Name>,
+    }
+
+    let person = Person {
+        name: Name("Jörg".to_string()),
+        aliases: vec![Name("jö".to_string())],
+    };
+    assert!(person.validate().is_ok());
+
+    let person = Person {
+        name: Name("J".to_string()),
+        aliases: vec![Name("jörg".to_string())],
+    };
+    let err = person.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].params()["actual"], 1);
+    assert_eq!(errs[1].code(), "contains");
+    assert_eq!(errs[2].location(), "/aliases/0");
+    assert_eq!(errs[2].params()["actual"], 4);
+}
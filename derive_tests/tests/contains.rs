@@ -89,7 +89,7 @@ fn validates_struct_vec() {
     assert_eq!(errs.len(), 1);
     assert_eq!(errs[0].code(), "contains");
     assert_eq!(errs[0].location(), "/val");
-    assert!(errs[0].params().get("target").is_none());
+    assert!(!errs[0].params().contains_key("target"));
 
     let s = TestStruct {
         val: vec![
@@ -135,3 +135,40 @@ fn can_specify_message_for_contains() {
     assert_eq!(errs.len(), 1);
     assert_eq!(errs[0].clone().message().unwrap(), "oops");
 }
+
+const WORD: &str = "dog";
+
+#[test]
+fn can_validate_contains_word() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(contains_word("cat"))]
+        val: String,
+        #[validate(contains_word(value = WORD, code = "no_dog"))]
+        other: Option<String>,
+        #[validate(iter(contains_word("red fox")))]
+        list: Vec<String>,
+    }
+
+    let s = TestStruct {
+        val: "the cat sat".to_string(),
+        other: Some("hot-dog!".to_string()),
+        list: vec!["the red fox.".to_string()],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        val: "category".to_string(),
+        other: Some("dogma".to_string()),
+        list: vec!["red fox".to_string(), "redfox".to_string()],
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].code(), "contains_word");
+    assert_eq!(errs[0].location(), "/val");
+    assert_eq!(errs[0].params()["target"], "cat");
+    assert_eq!(errs[1].code(), "no_dog");
+    assert_eq!(errs[1].params()["target"], "dog");
+    assert_eq!(errs[2].location(), "/list/1");
+}
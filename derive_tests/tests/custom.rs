@@ -86,3 +86,222 @@ fn can_specify_message_for_custom_fn() {
     assert_eq!(errs.len(), 1);
     assert_eq!(errs[0].clone().message().unwrap(), "oops");
 }
+
+fn slow_custom_fn(_: &str) -> Result<(), ValidationError> {
+    std::thread::sleep(std::time::Duration::from_millis(500));
+    Ok(())
+}
+
+#[test]
+fn slow_custom_fn_times_out() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom(function = slow_custom_fn, timeout_ms = 20))]
+        slow: String,
+        #[validate(custom(function = invalid_custom_fn, timeout_ms = 1000))]
+        fast: String,
+        #[validate(iter(custom(function = valid_custom_fn, timeout_ms = 1000)))]
+        list: Vec<String>,
+    }
+
+    let s = TestStruct {
+        slow: String::new(),
+        fast: String::new(),
+        list: vec!["a".to_string()],
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "custom_timeout");
+    assert_eq!(errs[0].location(), "/slow");
+    assert_eq!(errs[0].params()["timeout_ms"], 20);
+    assert_eq!(errs[1].code(), "meh");
+    assert_eq!(errs[1].location(), "/fast");
+}
+
+fn not_root(name: &str) -> Result<(), ValidationError> {
+    if name == "root" {
+        Err(ValidationError::new_field("reserved"))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_runs_only_in_its_groups() {
+    #[derive(Debug, Validate)]
+    struct User {
+        #[validate(custom(function = not_root, groups = ["admin", "staff"]))]
+        #[validate(length(min = 3))]
+        name: String,
+        #[validate]
+        nested: Option<NestedUser>,
+    }
+
+    #[derive(Debug, Validate)]
+    struct NestedUser {
+        #[validate(custom(function = not_root, groups = ["admin"]))]
+        name: String,
+    }
+
+    let user = User {
+        name: "root".to_string(),
+        nested: Some(NestedUser {
+            name: "root".to_string(),
+        }),
+    };
+
+    assert!(user.validate().is_ok());
+    assert!(user.validate_groups(&["user"]).is_ok());
+
+    let err = user.validate_groups(&["staff"]).unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(err.errors()[0].code(), "reserved");
+    assert_eq!(err.errors()[0].location(), "/name");
+
+    let err = user.validate_groups(&["user", "admin"]).unwrap_err();
+    assert_eq!(err.errors().len(), 2);
+    assert_eq!(err.errors()[0].location(), "/name");
+    assert_eq!(err.errors()[1].location(), "/nested/name");
+
+    // Validations without groups always run
+    let user = User {
+        name: "ro".to_string(),
+        nested: None,
+    };
+    let err = user.validate_groups(&["user"]).unwrap_err();
+    assert_eq!(err.errors()[0].code(), "length");
+}
+
+#[derive(Debug)]
+enum UsernameError {
+    Reserved,
+    Taken,
+}
+
+impl From<UsernameError> for ValidationError {
+    fn from(err: UsernameError) -> Self {
+        match err {
+            UsernameError::Reserved => ValidationError::new_field("reserved"),
+            UsernameError::Taken => ValidationError::new_field("taken"),
+        }
+    }
+}
+
+fn available_username(name: &str) -> Result<(), UsernameError> {
+    match name {
+        "admin" => Err(UsernameError::Reserved),
+        "bob" => Err(UsernameError::Taken),
+        _ => Ok(()),
+    }
+}
+
+#[test]
+fn custom_fn_errors_are_converted_into_validation_errors() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom(available_username))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "alice".to_string(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        val: "admin".to_string(),
+    };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "reserved");
+    assert_eq!(errs[0].location(), "/val");
+
+    let s = TestStruct {
+        val: "bob".to_string(),
+    };
+    let errs = s.validate().unwrap_err();
+    assert_eq!(errs.field_errors()[0].code(), "taken");
+}
+
+fn capitalized(name: &str) -> Result<(), ValidationError> {
+    match name.chars().next() {
+        Some(c) if c.is_uppercase() => Ok(()),
+        _ => Err(ValidationError::new_field("not_capitalized")),
+    }
+}
+
+#[test]
+fn run_last_custom_fn_runs_after_built_ins() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom(function = capitalized, run_last), length(min = 1))]
+        val: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Declared {
+        #[validate(custom(function = capitalized), length(min = 1))]
+        val: String,
+    }
+
+    let s = TestStruct {
+        val: "Alice".to_string(),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct { val: String::new() };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[1].code(), "not_capitalized");
+
+    // Without `run_last` the declaration order is kept
+    let s = Declared { val: String::new() };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs[0].code(), "not_capitalized");
+    assert_eq!(errs[1].code(), "length");
+}
+
+#[derive(Debug, Clone)]
+struct Chunks(Vec<String>);
+
+fn joins_under_limit(chunks: Chunks) -> Result<(), ValidationError> {
+    let joined: String = chunks.0.into_iter().collect();
+    if joined.len() > 8 {
+        return Err(ValidationError::new_field("too_long"));
+    }
+    Ok(())
+}
+
+#[test]
+fn by_value_custom_fn_receives_a_clone() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(custom(function = joins_under_limit, by_value))]
+        chunks: Chunks,
+        #[validate(custom(function = joins_under_limit, by_value))]
+        optional: Option<Chunks>,
+    }
+
+    let s = TestStruct {
+        chunks: Chunks(vec!["abc".to_string(), "def".to_string()]),
+        optional: None,
+    };
+    assert!(s.validate().is_ok());
+    assert_eq!(s.chunks.0.len(), 2);
+
+    let s = TestStruct {
+        chunks: Chunks(vec!["abcde".to_string(), "fghij".to_string()]),
+        optional: Some(Chunks(vec!["abcdefghij".to_string()])),
+    };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "too_long");
+    assert_eq!(errs[0].location(), "/chunks");
+    assert_eq!(errs[1].location(), "/optional");
+}
@@ -0,0 +1,44 @@
+use validify::Validate;
+
+#[test]
+fn drops_identical_errors_on_a_field() {
+    #[derive(Debug, Validate)]
+    #[validate(dedup_errors)]
+    struct Deduped {
+        #[validate(
+            length(min = 3, code = "invalid_name", message = "Invalid name"),
+            regex(pattern = "^[a-z]+$", code = "invalid_name", message = "Invalid name")
+        )]
+        name: String,
+        #[validate(length(min = 3, code = "invalid_name", message = "Invalid name"))]
+        other: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Duplicated {
+        #[validate(
+            length(min = 3, code = "invalid_name", message = "Invalid name"),
+            regex(pattern = "^[a-z]+$", code = "invalid_name", message = "Invalid name")
+        )]
+        name: String,
+    }
+
+    let err = Deduped {
+        name: "A".to_string(),
+        other: "B".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+    let errs = err.errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].location(), "/name");
+    assert_eq!(errs[0].params()["min"], 3);
+    assert_eq!(errs[1].location(), "/other");
+
+    let err = Duplicated {
+        name: "A".to_string(),
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(err.errors().len(), 2);
+}
@@ -70,3 +70,33 @@ fn can_specify_message_for_email() {
     assert_eq!(errs.len(), 1);
     assert_eq!(errs[0].clone().message().unwrap(), "oops");
 }
+
+#[test]
+fn require_tld_rejects_domains_without_tld() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(email)]
+        val: String,
+        #[validate(email(require_tld))]
+        val_tld: String,
+    }
+
+    let s = TestStruct {
+        val: "user@localhost".to_string(),
+        val_tld: "user@localhost".to_string(),
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "email");
+    assert_eq!(errs[0].location(), "/val_tld");
+    assert_eq!(errs[0].params()["domain"], "localhost");
+
+    let s = TestStruct {
+        val: "user@localhost".to_string(),
+        val_tld: "user@example.com".to_string(),
+    };
+    assert!(s.validate().is_ok());
+}
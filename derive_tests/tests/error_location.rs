@@ -1,5 +1,5 @@
 use std::collections::{HashMap, HashSet};
-use validify::{Validate, ValidationError};
+use validify::{Validate, ValidationError, Validify};
 
 #[test]
 fn with_custom_validation() {
@@ -188,3 +188,86 @@ fn allowed(n: &usize) -> Result<(), ValidationError> {
         Ok(())
     }
 }
+
+#[test]
+fn first_error_is_earliest_declared() {
+    #[derive(Debug, Validate)]
+    #[validate(schema_fail)]
+    struct Test {
+        #[validate(length(min = 5))]
+        a: String,
+        #[validate(range(max = 1.))]
+        b: usize,
+        #[validate]
+        c: Child,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Child {
+        #[validate(contains("x"))]
+        d: String,
+    }
+
+    fn schema_fail(_: &Test) -> Result<(), validify::ValidationErrors> {
+        let mut errors = validify::ValidationErrors::new();
+        errors.add(ValidationError::new_schema("schema"));
+        Err(errors)
+    }
+
+    let test = Test {
+        a: "abc".to_string(),
+        b: 2,
+        c: Child { d: "y".to_string() },
+    };
+    let err = test.validate().unwrap_err();
+    let (location, first) = err.first_error().unwrap();
+    assert_eq!(location, "/a");
+    assert_eq!(first.code(), "length");
+
+    let test = Test {
+        a: "abcdef".to_string(),
+        b: 2,
+        c: Child { d: "y".to_string() },
+    };
+    let err = test.validate().unwrap_err();
+    let (location, first) = err.first_error().unwrap();
+    assert_eq!(location, "/b");
+    assert_eq!(first.code(), "range");
+
+    assert!(validify::ValidationErrors::new().first_error().is_none());
+}
+
+#[test]
+fn first_error_of_validify_is_from_nested_fields() {
+    #[derive(Debug, Validify)]
+    struct Test {
+        #[validate(length(min = 5))]
+        a: String,
+        #[validify]
+        c: Child,
+    }
+
+    #[derive(Debug, Validify)]
+    struct Child {
+        #[modify(trim)]
+        #[validate(contains("x"))]
+        d: String,
+    }
+
+    let mut test = Test {
+        a: "abc".to_string(),
+        c: Child {
+            d: " y ".to_string(),
+        },
+    };
+    let err = test.validify().unwrap_err();
+    assert_eq!(err.field_errors().len(), 2);
+    let (location, first) = err.first_error().unwrap();
+    assert_eq!(location, "/c/d");
+    assert_eq!(first.code(), "contains");
+
+    // Validating without modifiers keeps the declaration order
+    let err = test.validate().unwrap_err();
+    let (location, _) = err.first_error().unwrap();
+    assert_eq!(location, "/a");
+}
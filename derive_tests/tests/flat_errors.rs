@@ -0,0 +1,48 @@
+use validify::Validate;
+
+#[derive(Debug, Validate)]
+struct Address {
+    #[validate(length(min = 2))]
+    city: String,
+}
+
+#[derive(Debug, Validate)]
+#[validate(flat_errors)]
+struct User {
+    #[validate(email)]
+    email: String,
+    #[validate]
+    address: Address,
+}
+
+#[test]
+fn flattens_nested_errors_into_dotted_paths() {
+    let user = User {
+        email: "nope".to_string(),
+        address: Address {
+            city: "Z".to_string(),
+        },
+    };
+
+    let errs = user.validate_flat();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].0, "email");
+    assert_eq!(errs[0].1.code(), "email");
+    assert_eq!(errs[1].0, "address.city");
+    assert_eq!(errs[1].1.code(), "length");
+
+    // The trait method is unchanged
+    assert_eq!(user.validate().unwrap_err().errors().len(), 2);
+}
+
+#[test]
+fn valid_struct_has_no_flat_errors() {
+    let user = User {
+        email: "user@example.com".to_string(),
+        address: Address {
+            city: "Zagreb".to_string(),
+        },
+    };
+
+    assert!(user.validate_flat().is_empty());
+}
@@ -0,0 +1,53 @@
+use validify::{Validate, Validify};
+
+mod normalize {
+    pub mod email {
+        pub fn lowercase(email: &mut String) {
+            *email = email.to_lowercase();
+        }
+    }
+}
+
+mod checks {
+    use validify::ValidationError;
+
+    pub fn not_admin(email: &str) -> Result<(), ValidationError> {
+        if email.starts_with("admin@") {
+            return Err(ValidationError::new_field("reserved"));
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn custom_functions_can_be_referenced_by_path() {
+    #[derive(Debug, Validify)]
+    struct Signup {
+        #[modify(custom(function = "crate::normalize::email::lowercase"))]
+        #[validate(custom(function = "crate::checks::not_admin"))]
+        email: String,
+        #[modify(custom(function = normalize::email::lowercase))]
+        #[validate(custom(function = checks::not_admin))]
+        backup: String,
+    }
+
+    let mut signup = Signup {
+        email: "User@Example.com".to_string(),
+        backup: "Other@Example.com".to_string(),
+    };
+    assert!(signup.validify().is_ok());
+    assert_eq!(signup.email, "user@example.com");
+    assert_eq!(signup.backup, "other@example.com");
+
+    let mut signup = Signup {
+        email: "ADMIN@example.com".to_string(),
+        backup: "admin@example.com".to_string(),
+    };
+    let err = signup.validify().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "reserved");
+    assert_eq!(errs[0].location(), "/email");
+    assert_eq!(errs[1].location(), "/backup");
+    assert!(signup.validate().is_err());
+}
@@ -0,0 +1,131 @@
+use validify::{Modify, Validify};
+
+#[derive(Debug, Validify)]
+struct Hashed {
+    #[modify(trim, hash(algo = "sha256"))]
+    a: String,
+    #[modify(hash)]
+    b: Option<String>,
+    #[modify(hash(algo = "sha512"))]
+    c: Vec<String>,
+}
+
+#[derive(Debug, Validify)]
+struct HashedAfterValidation {
+    #[modify(trim, hash(algo = "sha256", post_validate))]
+    #[validate(length(min = 8))]
+    password: String,
+}
+
+#[derive(Debug, Validify)]
+struct Credentials {
+    #[modify(hash(post_validate))]
+    #[validate(length(max = 20))]
+    password: String,
+}
+
+#[derive(Debug, Validify)]
+struct Account {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validify]
+    credentials: Credentials,
+    #[validify]
+    backup: Option<Credentials>,
+    #[validify]
+    history: Vec<Credentials>,
+}
+
+#[test]
+fn hash_modifier() {
+    let mut test = Hashed {
+        a: "  hello ".to_string(),
+        b: Some("hello".to_string()),
+        c: vec!["hello".to_string()],
+    };
+
+    test.modify();
+
+    assert_eq!(test.a.len(), 64);
+    assert!(test.a.chars().all(|c| c.is_ascii_hexdigit()));
+    assert_eq!(
+        test.a,
+        "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+    );
+    assert_eq!(test.b.as_deref(), Some(test.a.as_str()));
+    assert_eq!(test.c[0].len(), 128);
+
+    let mut test = Hashed {
+        a: String::new(),
+        b: None,
+        c: vec![],
+    };
+    test.modify();
+    assert_eq!(test.b, None);
+}
+
+#[test]
+fn hash_modifier_post_validate() {
+    let mut test = HashedAfterValidation {
+        password: "  supersecret  ".to_string(),
+    };
+
+    // Post validation modifiers do not run in `modify`
+    test.modify();
+    assert_eq!(test.password, "supersecret");
+
+    let res = test.validify();
+    assert!(res.is_ok());
+    assert_eq!(test.password.len(), 64);
+    assert!(test.password.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn hash_modifier_post_validate_skipped_on_failure() {
+    // Would pass the length check if hashed before validation
+    let mut test = HashedAfterValidation {
+        password: "  short  ".to_string(),
+    };
+
+    let res = test.validify();
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().errors()[0].code(), "length");
+    assert_eq!(test.password, "short");
+}
+
+#[test]
+fn hash_modifier_post_validate_nested() {
+    let credentials = |password: &str| Credentials {
+        password: password.to_string(),
+    };
+    let mut test = Account {
+        name: "account".to_string(),
+        credentials: credentials("supersecret"),
+        backup: Some(credentials("backupsecret")),
+        history: vec![credentials("oldsecret")],
+    };
+
+    // Nested hashes must not be validated again by the parent
+    let res = test.validify();
+    assert!(res.is_ok());
+    assert_eq!(test.credentials.password.len(), 64);
+    assert_eq!(test.backup.unwrap().password.len(), 64);
+    assert_eq!(test.history[0].password.len(), 64);
+}
+
+#[test]
+fn hash_modifier_post_validate_nested_skipped_on_parent_failure() {
+    let mut test = Account {
+        name: String::new(),
+        credentials: Credentials {
+            password: "supersecret".to_string(),
+        },
+        backup: None,
+        history: vec![],
+    };
+
+    let res = test.validify();
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().errors()[0].location(), "/name");
+    assert_eq!(test.credentials.password, "supersecret");
+}
@@ -0,0 +1,84 @@
+use indexmap::{IndexMap, IndexSet};
+use validify::Validate;
+
+#[test]
+fn validates_index_map_length() {
+    #[derive(Debug, Validate)]
+    struct Config {
+        #[validate(length(min = 1))]
+        entries: IndexMap<String, String>,
+    }
+
+    let config = Config {
+        entries: IndexMap::new(),
+    };
+
+    let err = config.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].location(), "/entries");
+
+    let config = Config {
+        entries: IndexMap::from([("key".to_string(), "value".to_string())]),
+    };
+
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validates_index_set_length_and_elements() {
+    #[derive(Debug, Validate)]
+    struct Tags {
+        #[validate(length(max = 2), iter(length(min = 2)))]
+        tags: IndexSet<String>,
+    }
+
+    let tags = Tags {
+        tags: IndexSet::from(["ok".to_string(), "a".to_string(), "b".to_string()]),
+    };
+
+    let err = tags.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].location(), "/tags");
+    assert_eq!(errs[1].location(), "/tags/1");
+    assert_eq!(errs[2].location(), "/tags/2");
+}
+
+#[test]
+fn validates_nested_index_map_values() {
+    #[derive(Debug, Validate)]
+    struct Entry {
+        #[validate(length(min = 1))]
+        value: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Config {
+        #[validate]
+        entries: IndexMap<String, Entry>,
+    }
+
+    let config = Config {
+        entries: IndexMap::from([
+            (
+                "first".to_string(),
+                Entry {
+                    value: "1".to_string(),
+                },
+            ),
+            (
+                "second".to_string(),
+                Entry {
+                    value: "".to_string(),
+                },
+            ),
+        ]),
+    };
+
+    let err = config.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].location(), "/entries/second/value");
+}
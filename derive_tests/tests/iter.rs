@@ -1,5 +1,6 @@
 use chrono::NaiveDate;
 use regex::Regex;
+use std::collections::HashMap;
 use validify::{field_err, Validate, ValidationError};
 
 #[test]
@@ -381,3 +382,75 @@ fn time_iter_works() {
     assert_eq!("/test/1", error.location());
     assert_eq!("before_or_equal", error.code());
 }
+
+#[test]
+fn validates_map_values_located_by_key() {
+    #[derive(Debug, Validate)]
+    struct Config {
+        #[validate(values(range(min = 0.)))]
+        limits: HashMap<String, i32>,
+    }
+
+    let config = Config {
+        limits: HashMap::from([("cpu".to_string(), 2), ("memory".to_string(), -1)]),
+    };
+
+    let err = config.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "range");
+    assert_eq!(errs[0].location(), "/limits/memory");
+    assert_eq!(errs[0].params()["actual"], -1.);
+
+    let config = Config {
+        limits: HashMap::from([("cpu".to_string(), 2)]),
+    };
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validates_each_tuple_element_located_by_index() {
+    #[derive(Debug, Validate)]
+    struct Color {
+        #[validate(each(range(max = 200.)))]
+        rgb: (u8, u8, u8),
+    }
+
+    let err = Color { rgb: (10, 250, 30) }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "range");
+    assert_eq!(errs[0].location(), "/rgb/1");
+    assert_eq!(errs[0].params()["actual"], 250);
+
+    assert!(Color { rgb: (10, 20, 30) }.validate().is_ok());
+}
+
+#[test]
+fn each_entry_validates_keys_and_values_of_pairs() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(each_entry(key(length(max = 10)), value(range(min = 0.), range(max = 100.))))]
+        scores: Vec<(String, i32)>,
+    }
+
+    let s = TestStruct {
+        scores: vec![("alice".to_string(), 10), ("bob".to_string(), 20)],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        scores: vec![
+            ("alice".to_string(), 10),
+            ("bob".to_string(), -5),
+            ("a very long name".to_string(), 30),
+        ],
+    };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "range");
+    assert_eq!(errs[0].location(), "/scores/1/value");
+    assert_eq!(errs[1].code(), "length");
+    assert_eq!(errs[1].location(), "/scores/2/key");
+}
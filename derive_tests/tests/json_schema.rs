@@ -0,0 +1,34 @@
+use serde_json::{json, Value};
+use validify::Validate;
+
+#[test]
+fn emits_schema_for_field_validators() {
+    #[derive(Debug, Validate)]
+    #[validate(json_schema)]
+    #[allow(dead_code)]
+    struct Signup {
+        #[validate(email, length(max = 64))]
+        email: String,
+        #[validate(length(min = 3, max = 16), regex("^[a-z]+$"))]
+        username: String,
+        #[validate(length(equal = 4))]
+        pin: String,
+        #[validate(range(min = 18., max = 120.))]
+        age: u8,
+        nickname: Option<String>,
+    }
+
+    let schema: Value = serde_json::from_str(Signup::JSON_SCHEMA).unwrap();
+    assert_eq!(
+        schema,
+        json!({
+            "type": "object",
+            "properties": {
+                "email": { "format": "email", "maxLength": 64 },
+                "username": { "minLength": 3, "maxLength": 16, "pattern": "^[a-z]+$" },
+                "pin": { "minLength": 4, "maxLength": 4 },
+                "age": { "minimum": 18.0, "maximum": 120.0 },
+            }
+        })
+    );
+}
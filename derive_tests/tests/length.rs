@@ -213,3 +213,116 @@ fn can_validate_vec_of_primitives() {
     };
     assert!(test.validate().is_ok())
 }
+
+mod config {
+    use once_cell::sync::Lazy;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    pub static INITIALIZED: AtomicUsize = AtomicUsize::new(0);
+
+    pub static MAX_NAME: Lazy<usize> = Lazy::new(|| {
+        INITIALIZED.fetch_add(1, Ordering::SeqCst);
+        5
+    });
+}
+
+#[test]
+fn validate_length_with_lazy_bound() {
+    use std::sync::atomic::Ordering;
+
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(min = 1, max_from = "config::MAX_NAME"))]
+        val: String,
+    }
+
+    let test = TestStruct {
+        val: "hello".to_string(),
+    };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        val: "hello world".to_string(),
+    };
+    let res = test.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].params()["min"], 1);
+    assert_eq!(errs[0].params()["max"], 5);
+    assert_eq!(errs[0].params()["actual"], 11);
+
+    assert_eq!(config::INITIALIZED.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn none_counts_as_empty_with_none_is_zero() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(min = 1, none_is_zero))]
+        zeroed: Option<Vec<u8>>,
+        #[validate(length(min = 1))]
+        skipped: Option<Vec<u8>>,
+    }
+
+    let test = TestStruct {
+        zeroed: Some(vec![1]),
+        skipped: None,
+    };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        zeroed: None,
+        skipped: None,
+    };
+    let err = test.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].location(), "/zeroed");
+    assert_eq!(errs[0].params()["min"], 1);
+    assert_eq!(errs[0].params()["actual"], 0);
+
+    let test = TestStruct {
+        zeroed: Some(vec![]),
+        skipped: Some(vec![]),
+    };
+    assert_eq!(test.validate().unwrap_err().field_errors().len(), 2);
+}
+
+#[test]
+fn ignore_whitespace_counts_only_content() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(length(max = 5, ignore_whitespace))]
+        content: String,
+        #[validate(length(min = 2, ignore_whitespace, none_is_zero))]
+        note: Option<String>,
+        #[validate(iter(length(equal = 2, ignore_whitespace)))]
+        codes: Vec<String>,
+    }
+
+    // 13 chars, 5 of which are not whitespace
+    let s = TestStruct {
+        content: "  h e\tl l o  ".to_string(),
+        note: Some(" a b ".to_string()),
+        codes: vec![" x y ".to_string()],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        content: "  hello world  ".to_string(),
+        note: None,
+        codes: vec!["x     ".to_string()],
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].location(), "/content");
+    assert_eq!(errs[0].params()["actual"], 10);
+    assert_eq!(errs[1].location(), "/note");
+    assert_eq!(errs[2].location(), "/codes/0");
+    assert_eq!(errs[2].params()["actual"], 1);
+}
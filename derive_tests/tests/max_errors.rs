@@ -0,0 +1,112 @@
+use validify::{Validate, ValidationError, ValidationErrors, MAX_ERRORS_CODE};
+
+#[derive(Debug, Validate)]
+#[validate(max_errors = 100)]
+struct Capped {
+    #[validate(iter(range(max = 10)))]
+    values: Vec<u32>,
+    #[validate(length(min = 1))]
+    name: String,
+}
+
+#[test]
+fn stops_collecting_errors_at_the_cap() {
+    let s = Capped {
+        values: vec![11; 100_000],
+        name: String::new(),
+    };
+    let errs = s.validate().unwrap_err();
+    assert!(errs.is_truncated());
+    assert_eq!(errs.errors().len(), 101);
+    assert_eq!(errs.field_errors().len(), 100);
+    assert!(errs.field_errors().iter().all(|err| err.code() == "range"));
+    let schema_errs = errs.schema_errors();
+    assert_eq!(schema_errs.len(), 1);
+    assert_eq!(schema_errs[0].code(), MAX_ERRORS_CODE);
+}
+
+#[test]
+fn errors_under_the_cap_are_not_truncated() {
+    let s = Capped {
+        values: vec![11; 99],
+        name: String::new(),
+    };
+    let errs = s.validate().unwrap_err();
+    assert!(!errs.is_truncated());
+    assert_eq!(errs.errors().len(), 100);
+    assert_eq!(errs.field_errors()[99].code(), "length");
+
+    let s = Capped {
+        values: vec![1; 1000],
+        name: "ok".to_string(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn unbounded_by_default() {
+    #[derive(Debug, Validate)]
+    struct Unbounded {
+        #[validate(iter(range(max = 10)))]
+        values: Vec<u32>,
+    }
+
+    let s = Unbounded {
+        values: vec![11; 1000],
+    };
+    let errs = s.validate().unwrap_err();
+    assert!(!errs.is_truncated());
+    assert_eq!(errs.errors().len(), 1000);
+}
+
+#[test]
+fn merged_errors_respect_the_cap() {
+    let mut errs = ValidationErrors::with_max_errors(2);
+    let mut other = ValidationErrors::new();
+    for code in ["a", "b", "c", "d"] {
+        other.add(ValidationError::new_field(code));
+    }
+    errs.merge(other);
+    assert!(errs.is_truncated());
+    assert_eq!(errs.field_errors().len(), 2);
+    assert_eq!(errs.errors()[2].code(), MAX_ERRORS_CODE);
+}
+
+#[test]
+fn cap_is_not_compared() {
+    let mut capped = ValidationErrors::with_max_errors(10);
+    let mut uncapped = ValidationErrors::new();
+    for errors in [&mut capped, &mut uncapped] {
+        errors.add(ValidationError::new_field("a"));
+    }
+    assert_eq!(capped, uncapped);
+
+    // Truncated errors differ from the same errors that were not truncated
+    let mut truncated = ValidationErrors::with_max_errors(1);
+    truncated.add(ValidationError::new_field("a"));
+    truncated.add(ValidationError::new_field("b"));
+    assert_ne!(truncated, uncapped);
+}
+
+#[test]
+fn merging_truncated_errors_keeps_the_flag() {
+    let mut child = ValidationErrors::with_max_errors(1);
+    child.add(ValidationError::new_field("a"));
+    child.add(ValidationError::new_field("b"));
+
+    let mut parent = ValidationErrors::new();
+    parent.merge(child);
+    assert!(parent.is_truncated());
+    assert_eq!(parent.errors().len(), 2);
+}
+
+#[test]
+fn serializes_as_a_list_with_the_marker() {
+    let mut errs = ValidationErrors::with_max_errors(1);
+    errs.add(ValidationError::new_field("a"));
+    errs.add(ValidationError::new_field("b"));
+    let json = serde_json::to_value(&errs).unwrap();
+    let json = json.as_array().unwrap();
+    assert_eq!(json.len(), 2);
+    assert_eq!(json[1]["Schema"]["code"], MAX_ERRORS_CODE);
+}
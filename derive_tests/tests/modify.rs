@@ -1,6 +1,7 @@
 use chrono::NaiveDate;
 use serde::Deserialize;
 use std::collections::HashMap;
+use std::ops::ControlFlow;
 use validify::{Modify, Payload, Validify, ValidifyPayload};
 
 fn do_something(input: &mut String) {
@@ -281,3 +282,344 @@ fn trim() {
     assert!(matches!(second.c, Some(a) if a == "WORKS"));
     assert_eq!(second.d, "WORKS");
 }
+
+#[test]
+fn sanitize_modifies_without_validating() {
+    #[derive(Debug, Validify)]
+    struct Sanitized {
+        #[modify(trim, lowercase)]
+        #[validate(email)]
+        email: String,
+        #[modify(trim)]
+        #[validate(length(min = 5))]
+        name: String,
+        #[validify]
+        nested: SanitizedNested,
+    }
+
+    #[derive(Debug, Validify)]
+    struct SanitizedNested {
+        #[modify(uppercase)]
+        #[validate(length(equal = 1))]
+        code: String,
+    }
+
+    let mut test = Sanitized {
+        email: "  NOT AN EMAIL ".to_string(),
+        name: " Bob ".to_string(),
+        nested: SanitizedNested {
+            code: "abc".to_string(),
+        },
+    };
+
+    test.sanitize();
+
+    assert_eq!(test.email, "not an email");
+    assert_eq!(test.name, "Bob");
+    assert_eq!(test.nested.code, "ABC");
+
+    assert!(test.validify().is_err());
+}
+
+fn normalize(input: &mut String) {
+    *input = input.trim().to_lowercase();
+}
+
+#[test]
+fn struct_level_custom_modifier_applies_to_fields() {
+    #[derive(Debug, Validify)]
+    #[modify(custom(function = "normalize", fields = ["a", "b", "c"]))]
+    struct Batch {
+        a: String,
+        #[modify(uppercase)]
+        b: String,
+        c: Option<String>,
+        d: String,
+    }
+
+    let mut test = Batch {
+        a: "  AAA ".to_string(),
+        b: " bBb  ".to_string(),
+        c: Some(" C ".to_string()),
+        d: " D ".to_string(),
+    };
+
+    assert!(test.validify().is_ok());
+    assert_eq!(test.a, "aaa");
+    assert_eq!(test.b, "bbb");
+    assert_eq!(test.c, Some("c".to_string()));
+    assert_eq!(test.d, " D ");
+}
+
+fn keep_sentinel(input: &mut String) -> ControlFlow<()> {
+    if input == "N/A" {
+        ControlFlow::Break(())
+    } else {
+        ControlFlow::Continue(())
+    }
+}
+
+#[test]
+fn control_flow_modifier_skips_remaining_modifiers() {
+    #[derive(Debug, Validify)]
+    struct Sentinel {
+        #[modify(trim, custom(function = keep_sentinel, control_flow), lowercase)]
+        a: String,
+        #[modify(custom(function = keep_sentinel, control_flow), custom(do_something))]
+        b: Option<String>,
+        #[modify(lowercase)]
+        c: String,
+    }
+
+    let mut test = Sentinel {
+        a: "  N/A ".to_string(),
+        b: Some("N/A".to_string()),
+        c: "LOWER".to_string(),
+    };
+    test.modify();
+
+    assert_eq!(test.a, "N/A");
+    assert_eq!(test.b, Some("N/A".to_string()));
+    assert_eq!(test.c, "lower");
+
+    let mut test = Sentinel {
+        a: "  VALUE ".to_string(),
+        b: Some("VALUE".to_string()),
+        c: "LOWER".to_string(),
+    };
+    test.modify();
+
+    assert_eq!(test.a, "value");
+    assert_eq!(test.b, Some("modified".to_string()));
+    assert_eq!(test.c, "lower");
+}
+
+const MAX_VOLUME: f64 = 1.5;
+
+#[test]
+fn clamp_modifier() {
+    #[derive(Debug, Validify)]
+    struct Clamped {
+        #[modify(clamp(min = 0, max = 10))]
+        a: i32,
+        #[modify(clamp(min = 0, max = 10))]
+        b: Option<i32>,
+        #[modify(clamp(min = 0, max = 10))]
+        c: Option<i32>,
+        #[modify(clamp(max = MAX_VOLUME))]
+        d: Option<Option<f64>>,
+        #[modify(clamp(min = -1))]
+        e: Vec<i64>,
+    }
+
+    let mut test = Clamped {
+        a: -5,
+        b: Some(50),
+        c: None,
+        d: Some(Some(3.)),
+        e: vec![-3, 0, 7],
+    };
+    test.modify();
+
+    assert_eq!(test.a, 0);
+    assert_eq!(test.b, Some(10));
+    assert_eq!(test.c, None);
+    assert_eq!(test.d, Some(Some(1.5)));
+    assert_eq!(test.e, vec![-1, 0, 7]);
+
+    let mut test = Clamped {
+        a: 5,
+        b: Some(5),
+        c: Some(-1),
+        d: Some(None),
+        e: vec![],
+    };
+    test.modify();
+
+    assert_eq!(test.a, 5);
+    assert_eq!(test.b, Some(5));
+    assert_eq!(test.c, Some(0));
+    assert_eq!(test.d, Some(None));
+}
+
+#[test]
+fn clamp_float_with_integer_bounds() {
+    #[derive(Debug, Validify)]
+    struct Clamped {
+        #[modify(clamp(min = 0, max = 10))]
+        a: f64,
+        #[modify(clamp(min = -1))]
+        b: Vec<f32>,
+    }
+
+    let mut test = Clamped {
+        a: 12.5,
+        b: vec![-3.5, 0.5],
+    };
+    test.modify();
+
+    assert_eq!(test.a, 10.);
+    assert_eq!(test.b, vec![-1., 0.5]);
+}
+
+#[test]
+fn grouped_modifiers_apply_only_in_their_groups() {
+    #[derive(Debug, Validify)]
+    struct Imported {
+        #[modify(trim)]
+        #[modify(lowercase, groups = ["import"])]
+        #[validate(length(min = 1))]
+        name: String,
+        #[modify(uppercase, groups = ["export"])]
+        tags: Vec<String>,
+    }
+
+    let mut test = Imported {
+        name: "  MiXeD  ".to_string(),
+        tags: vec!["a".to_string()],
+    };
+    test.validify().unwrap();
+    assert_eq!(test.name, "MiXeD");
+    assert_eq!(test.tags, vec!["a"]);
+
+    let mut test = Imported {
+        name: "  MiXeD  ".to_string(),
+        tags: vec!["a".to_string()],
+    };
+    test.validify_groups(&["import"]).unwrap();
+    assert_eq!(test.name, "mixed");
+    assert_eq!(test.tags, vec!["a"]);
+
+    test.modify_groups(&["export"]);
+    assert_eq!(test.tags, vec!["A"]);
+}
+
+#[test]
+fn preview_modifications_leaves_original_untouched() {
+    #[derive(Debug, Clone, Validify)]
+    struct Testor {
+        #[modify(trim, lowercase)]
+        email: String,
+        #[modify(trim)]
+        name: Option<String>,
+    }
+
+    let testor = Testor {
+        email: "  ALICE@Example.com ".to_string(),
+        name: Some(" Alice ".to_string()),
+    };
+
+    let preview = testor.preview_modifications();
+    assert_eq!(preview.email, "alice@example.com");
+    assert_eq!(preview.name.as_deref(), Some("Alice"));
+
+    assert_eq!(testor.email, "  ALICE@Example.com ");
+    assert_eq!(testor.name.as_deref(), Some(" Alice "));
+}
+
+fn normalize_tag(tag: &mut String) {
+    *tag = tag.trim().to_lowercase().replace(' ', "-");
+}
+
+#[test]
+fn par_map_matches_sequential_map() {
+    #[derive(Debug, Validify)]
+    struct Testor {
+        #[modify(map(function = "normalize_tag"))]
+        sequential: Vec<String>,
+        #[modify(par_map(function = "normalize_tag"))]
+        parallel: Vec<String>,
+        #[modify(par_map(function = "normalize_tag"))]
+        optional: Option<Vec<String>>,
+    }
+
+    let tags: Vec<String> = (0..1000).map(|i| format!("  Tag Number {i} ")).collect();
+    let mut testor = Testor {
+        sequential: tags.clone(),
+        parallel: tags.clone(),
+        optional: Some(tags),
+    };
+    testor.modify();
+
+    assert_eq!(testor.sequential[42], "tag-number-42");
+    assert_eq!(testor.parallel, testor.sequential);
+    assert_eq!(testor.optional.as_ref(), Some(&testor.sequential));
+}
+
+#[test]
+fn ensure_scheme_prepends_missing_scheme() {
+    #[derive(Debug, Validify)]
+    struct Links {
+        #[modify(trim, ensure_scheme = "https")]
+        #[validate(url)]
+        homepage: String,
+        #[modify(ensure_scheme = "https")]
+        blog: Option<String>,
+        #[modify(ensure_scheme = "ftp")]
+        mirrors: Vec<String>,
+    }
+
+    let mut links = Links {
+        homepage: "  example.com  ".to_string(),
+        blog: Some("http://blog.example.com".to_string()),
+        mirrors: vec!["files.example.com".to_string(), "sftp://x".to_string()],
+    };
+
+    assert!(links.validify().is_ok());
+    assert_eq!(links.homepage, "https://example.com");
+    assert_eq!(links.blog.as_deref(), Some("http://blog.example.com"));
+    assert_eq!(links.mirrors, ["ftp://files.example.com", "sftp://x"]);
+
+    let mut links = Links {
+        homepage: "http://x".to_string(),
+        blog: Some("blog.example.com".to_string()),
+        mirrors: vec![],
+    };
+
+    assert!(links.validify().is_ok());
+    assert_eq!(links.homepage, "http://x");
+    assert_eq!(links.blog.as_deref(), Some("https://blog.example.com"));
+}
+
+#[test]
+fn parse_number_strips_separators() {
+    #[derive(Debug, Validify)]
+    struct Amounts {
+        #[modify(trim, parse_number)]
+        total: String,
+        #[modify(parse_number(decimal_separator = ','))]
+        local: Option<String>,
+        #[modify(parse_number)]
+        items: Vec<String>,
+    }
+
+    let mut amounts = Amounts {
+        total: " 1,234.56 ".to_string(),
+        local: Some("1.234,56".to_string()),
+        items: vec!["1 000".to_string(), "-12,000.5".to_string()],
+    };
+
+    assert!(amounts.validify().is_ok());
+    assert_eq!(amounts.total, "1234.56");
+    assert_eq!(amounts.local.as_deref(), Some("1234.56"));
+    assert_eq!(amounts.items, ["1000", "-12000.5"]);
+
+    // Values that are not numbers are left as they are and rejected
+    let mut amounts = Amounts {
+        total: "1,2a".to_string(),
+        local: Some("1,2,3".to_string()),
+        items: vec!["1".to_string(), "one".to_string()],
+    };
+
+    let errs = amounts.validify().unwrap_err();
+    assert_eq!(amounts.total, "1,2a");
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].code(), "parse_number");
+    assert_eq!(errs[0].location(), "/total");
+    assert_eq!(errs[0].params()["actual"], "1,2a");
+    assert_eq!(errs[1].code(), "parse_number");
+    assert_eq!(errs[1].location(), "/local");
+    assert_eq!(errs[2].code(), "parse_number");
+    assert_eq!(errs[2].location(), "/items/1");
+}
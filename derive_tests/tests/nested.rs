@@ -1,5 +1,5 @@
 use std::collections::{HashMap, HashSet};
-use validify::Validate;
+use validify::{Validate, Validify};
 
 #[derive(Debug, Validate)]
 struct Root {
@@ -490,3 +490,155 @@ fn multiple_nested_hashmap_location_str() {
     assert_eq!(err.errors().len(), 1);
     assert_eq!(err.errors()[0].location(), "/child/children/four/allowance");
 }
+
+trait Rule: Validate {}
+
+#[derive(Debug, Validate)]
+struct MinLengthRule {
+    #[validate(length(min = 3))]
+    name: String,
+}
+
+impl Rule for MinLengthRule {}
+
+#[derive(Validate)]
+struct ParentWithOptionalBoxedTraitObject {
+    #[validate]
+    rule: Option<Box<dyn Rule>>,
+    #[validate]
+    rules: Option<Vec<Box<dyn Rule>>>,
+}
+
+#[test]
+fn validates_optional_boxed_trait_objects() {
+    let parent = ParentWithOptionalBoxedTraitObject {
+        rule: None,
+        rules: None,
+    };
+    assert!(parent.validate().is_ok());
+
+    let parent = ParentWithOptionalBoxedTraitObject {
+        rule: Some(Box::new(MinLengthRule {
+            name: "long enough".to_string(),
+        })),
+        rules: Some(vec![Box::new(MinLengthRule {
+            name: "also long enough".to_string(),
+        })]),
+    };
+    assert!(parent.validate().is_ok());
+
+    let parent = ParentWithOptionalBoxedTraitObject {
+        rule: Some(Box::new(MinLengthRule {
+            name: "no".to_string(),
+        })),
+        rules: Some(vec![
+            Box::new(MinLengthRule {
+                name: "fine".to_string(),
+            }),
+            Box::new(MinLengthRule {
+                name: "no".to_string(),
+            }),
+        ]),
+    };
+    let err = parent.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 2);
+    assert_eq!(err.errors()[0].location(), "/rule/name");
+    assert_eq!(err.errors()[0].code(), "length");
+    assert_eq!(err.errors()[1].location(), "/rules/1/name");
+}
+
+#[test]
+fn failed_fields_lists_dotted_paths_once() {
+    #[derive(Debug, Validate)]
+    struct Address {
+        #[validate(length(min = 1))]
+        city: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Signup {
+        #[validate(length(min = 1), contains("a"))]
+        name: String,
+        #[validate(length(min = 1))]
+        nickname: String,
+        #[validate(email)]
+        email: String,
+        #[validate]
+        address: Address,
+    }
+
+    let signup = Signup {
+        name: String::new(),
+        nickname: "bob".to_string(),
+        email: "bob".to_string(),
+        address: Address {
+            city: String::new(),
+        },
+    };
+
+    let err = signup.validate().unwrap_err();
+    assert_eq!(err.failed_fields(), vec!["name", "email", "address.city"]);
+}
+
+#[test]
+#[allow(clippy::vec_box)]
+fn validates_deeply_wrapped_boxed_children() {
+    #[derive(Debug, Validify)]
+    struct Child {
+        #[modify(trim)]
+        #[validate(length(min = 2))]
+        name: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Parent {
+        #[validate(nested)]
+        children: Option<Vec<Box<Child>>>,
+    }
+
+    #[derive(Debug, Validify)]
+    struct ValidifiedParent {
+        #[validify]
+        children: Option<Vec<Box<Child>>>,
+    }
+
+    assert!(Parent { children: None }.validate().is_ok());
+    assert!(Parent {
+        children: Some(vec![])
+    }
+    .validate()
+    .is_ok());
+
+    let parent = Parent {
+        children: Some(vec![
+            Box::new(Child {
+                name: "Ana".to_string(),
+            }),
+            Box::new(Child {
+                name: "B".to_string(),
+            }),
+        ]),
+    };
+    let err = parent.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(err.errors()[0].location(), "/children/1/name");
+    assert_eq!(err.errors()[0].code(), "length");
+
+    let mut parent = ValidifiedParent {
+        children: Some(vec![
+            Box::new(Child {
+                name: " Ana ".to_string(),
+            }),
+            Box::new(Child {
+                name: " B ".to_string(),
+            }),
+        ]),
+    };
+    let err = parent.validify().unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(err.errors()[0].location(), "/children/1/name");
+    assert_eq!(parent.children.as_ref().unwrap()[0].name, "Ana");
+
+    let mut parent = ValidifiedParent { children: None };
+    assert!(parent.validify().is_ok());
+}
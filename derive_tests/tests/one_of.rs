@@ -0,0 +1,208 @@
+use serde_json::json;
+use validify::Validate;
+
+#[test]
+fn one_of_is_case_sensitive_by_default() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(["draft", "published"]))]
+        status: String,
+    }
+
+    let test = TestStruct {
+        status: "draft".to_string(),
+    };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        status: "Draft".to_string(),
+    };
+    let res = test.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "one_of");
+    assert_eq!(errs[0].params()["actual"], "Draft");
+    assert_eq!(errs[0].params()["allowed"], json!(["draft", "published"]));
+}
+
+#[test]
+fn one_of_case_insensitive() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(["draft", "Published"], case_insensitive))]
+        status: String,
+    }
+
+    let test = TestStruct {
+        status: "Draft".to_string(),
+    };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        status: "PUBLISHED".to_string(),
+    };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        status: "Archived".to_string(),
+    };
+    let res = test.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "one_of");
+    assert_eq!(errs[0].params()["actual"], "archived");
+    assert_eq!(errs[0].params()["allowed"], json!(["draft", "published"]));
+}
+
+#[test]
+fn one_of_with_code_and_message() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(["draft"], case_insensitive, code = "status", message = "bad status"))]
+        status: Option<String>,
+    }
+
+    let test = TestStruct { status: None };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        status: Some("DRAFT".to_string()),
+    };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        status: Some("other".to_string()),
+    };
+    let err = test.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs[0].code(), "status");
+    assert_eq!(errs[0].message(), Some("bad status".to_string()));
+}
+
+#[test]
+fn one_of_in_iter() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(iter(one_of(["a", "b"])))]
+        values: Vec<String>,
+    }
+
+    let test = TestStruct {
+        values: vec!["a".to_string(), "c".to_string()],
+    };
+    let err = test.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].location(), "/values/1");
+}
+
+#[derive(Debug, validify::Discriminants)]
+#[repr(u8)]
+#[allow(dead_code)]
+enum Color {
+    Red = 1,
+    Green = 2,
+    Blue = 4,
+}
+
+#[derive(Debug, validify::Discriminants)]
+#[allow(dead_code)]
+enum Implicit {
+    A,
+    B,
+    C = 10,
+    D,
+}
+
+#[test]
+fn one_of_enum_discriminants() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(enum_discriminants = "Color"))]
+        color: u8,
+        #[validate(one_of(enum_discriminants = "Implicit"))]
+        implicit: Option<i32>,
+        #[validate(iter(one_of(enum_discriminants = "Color", code = "palette")))]
+        palette: Vec<u8>,
+    }
+
+    let test = TestStruct {
+        color: 4,
+        implicit: Some(11),
+        palette: vec![1, 2],
+    };
+    assert!(test.validate().is_ok());
+
+    let test = TestStruct {
+        color: 3,
+        implicit: Some(2),
+        palette: vec![1, 8],
+    };
+    let err = test.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+
+    assert_eq!(errs[0].code(), "one_of");
+    assert_eq!(errs[0].location(), "/color");
+    assert_eq!(errs[0].params()["actual"], 3);
+    assert_eq!(errs[0].params()["allowed"], json!([1, 2, 4]));
+
+    assert_eq!(errs[1].location(), "/implicit");
+    assert_eq!(errs[1].params()["allowed"], json!([0, 1, 10, 11]));
+
+    assert_eq!(errs[2].code(), "palette");
+    assert_eq!(errs[2].location(), "/palette/1");
+    assert_eq!(errs[2].params()["actual"], 8);
+}
+
+#[derive(Debug, validify::Discriminants)]
+#[repr(u64)]
+#[allow(dead_code)]
+enum Wide {
+    Small = 1,
+    Large = u64::MAX,
+}
+
+#[derive(Debug, validify::Discriminants)]
+#[repr(i64)]
+#[allow(dead_code)]
+enum Signed {
+    Negative = -1,
+    Positive = 1,
+}
+
+#[test]
+fn one_of_enum_discriminants_does_not_wrap() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(one_of(enum_discriminants = "Wide"))]
+        wide: i64,
+        #[validate(one_of(enum_discriminants = "Signed"))]
+        signed: u64,
+        #[validate(one_of(enum_discriminants = "Wide"))]
+        large: u64,
+    }
+
+    let test = TestStruct {
+        wide: 1,
+        signed: 1,
+        large: u64::MAX,
+    };
+    assert!(test.validate().is_ok());
+
+    // Both `-1` and `u64::MAX` are `-1` when cast to an `i64`
+    let test = TestStruct {
+        wide: -1,
+        signed: u64::MAX,
+        large: u64::MAX,
+    };
+    let err = test.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].location(), "/wide");
+    assert_eq!(errs[1].location(), "/signed");
+}
@@ -0,0 +1,73 @@
+use validify::Validate;
+
+#[derive(Debug, Validate)]
+#[validate(partial)]
+struct UserPatch {
+    #[validate(required, length(min = 2, max = 20))]
+    name: Option<String>,
+    #[validate(email)]
+    email: Option<String>,
+    #[validate(length(min = 1, none_is_zero))]
+    tags: Option<Vec<String>>,
+    #[validate(range(min = 18, max = 150))]
+    age: Option<u8>,
+}
+
+#[test]
+fn absent_fields_are_not_validated() {
+    let patch = UserPatch {
+        name: None,
+        email: None,
+        tags: None,
+        age: None,
+    };
+    assert!(patch.validate().is_ok());
+
+    let patch = UserPatch {
+        name: None,
+        email: Some("alice@example.com".to_string()),
+        tags: None,
+        age: Some(30),
+    };
+    assert!(patch.validate().is_ok());
+}
+
+#[test]
+fn present_fields_are_validated() {
+    let patch = UserPatch {
+        name: Some("a".to_string()),
+        email: None,
+        tags: Some(vec![]),
+        age: Some(12),
+    };
+    let err = patch.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].location(), "/name");
+    assert_eq!(errs[1].code(), "length");
+    assert_eq!(errs[1].location(), "/tags");
+    assert_eq!(errs[2].code(), "range");
+    assert_eq!(errs[2].location(), "/age");
+}
+
+#[test]
+fn none_checks_apply_without_partial() {
+    #[derive(Debug, Validate)]
+    struct User {
+        #[validate(required, length(min = 2, max = 20))]
+        name: Option<String>,
+        #[validate(length(min = 1, none_is_zero))]
+        tags: Option<Vec<String>>,
+    }
+
+    let user = User {
+        name: None,
+        tags: None,
+    };
+    let err = user.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "required");
+    assert_eq!(errs[1].code(), "length");
+}
@@ -1,6 +1,6 @@
 use serde::Deserialize;
 use serde_json::json;
-use validify::{Payload, Validify, ValidifyPayload};
+use validify::{Payload, Validate, ValidationError, Validify, ValidifyPayload};
 
 #[test]
 fn nested() {
@@ -237,3 +237,144 @@ mod nest {
         a: String,
     }
 }
+
+fn parse_url(input: String) -> Result<url::Url, ValidationError> {
+    url::Url::parse(&input).map_err(|_| ValidationError::new_field("parse_url"))
+}
+
+#[test]
+fn converts_payload_fields_to_different_types() {
+    #[derive(Debug, Validify, Payload)]
+    struct Site {
+        #[modify(custom(function = parse_url, from = String))]
+        url: url::Url,
+        #[modify(custom(function = parse_url, from = String))]
+        mirror: Option<url::Url>,
+        #[modify(trim)]
+        #[validate(length(min = 1))]
+        name: String,
+    }
+
+    let payload = SitePayload {
+        url: Some("https://example.com/".to_string()),
+        mirror: None,
+        name: Some("  example ".to_string()),
+    };
+
+    let site = Site::validify_from(payload).unwrap();
+    assert_eq!(site.url, url::Url::parse("https://example.com/").unwrap());
+    assert_eq!(site.mirror, None);
+    assert_eq!(site.name, "example");
+
+    let payload = SitePayload::from(site);
+    assert_eq!(payload.url, Some("https://example.com/".to_string()));
+
+    let payload = SitePayload {
+        url: Some("not a url".to_string()),
+        mirror: Some("neither".to_string()),
+        name: Some("".to_string()),
+    };
+
+    let err = Site::validify_from(payload).unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "parse_url");
+    assert_eq!(errs[0].location(), "/url");
+    assert_eq!(errs[1].location(), "/mirror");
+
+    let payload = SitePayload {
+        url: None,
+        mirror: None,
+        name: None,
+    };
+
+    let err = Site::validate_from(payload).unwrap_err();
+    assert_eq!(err.field_errors().len(), 2);
+    assert_eq!(err.field_errors()[0].code(), "required");
+}
+
+#[test]
+fn missing_converted_field_fails_conversion() {
+    #[derive(Debug, Validify, Payload)]
+    struct Site {
+        #[modify(custom(function = parse_url, from = String))]
+        url: url::Url,
+        #[modify(custom(function = parse_url, from = String))]
+        mirror: Option<url::Url>,
+    }
+
+    let payload: SitePayload = serde_json::from_value(json!({ "mirror": null })).unwrap();
+    let err = Site::try_from(payload).unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "required");
+    assert_eq!(errs[0].location(), "/url");
+
+    let payload: SitePayload = serde_json::from_value(json!({})).unwrap();
+    let err = Site::validify_from(payload).unwrap_err();
+    assert_eq!(err.field_errors()[0].code(), "required");
+}
+
+fn default_retries() -> u8 {
+    3
+}
+
+#[test]
+fn nested_with_defaulted_fields() {
+    #[derive(Debug, Clone, Default, Deserialize, Validify, Payload)]
+    struct Settings {
+        #[serde(default)]
+        #[modify(trim)]
+        #[validate(length(max = 5))]
+        name: String,
+        #[serde(default)]
+        tags: Vec<String>,
+        #[serde(default = "default_retries")]
+        #[validate(range(max = 5.))]
+        retries: u8,
+        #[validate(range(max = 10.))]
+        count: Option<u8>,
+    }
+
+    #[derive(Debug, Clone, Deserialize, Validify, Payload)]
+    struct Testor {
+        #[serde(default)]
+        #[validify]
+        settings: Settings,
+        #[validify]
+        optional: Option<Settings>,
+        #[serde(default)]
+        #[validify]
+        list: Vec<Settings>,
+    }
+
+    let mut testor = Testor {
+        settings: Settings::default(),
+        optional: Some(Settings::default()),
+        list: vec![Settings::default()],
+    };
+    assert!(testor.validate().is_ok());
+    assert!(testor.validify().is_ok());
+
+    let json = json!({"settings": {}, "optional": {}, "list": [{}]});
+    let res = Testor::validify_from(serde_json::from_value::<TestorPayload>(json).unwrap());
+    let testor = res.unwrap();
+    assert_eq!(testor.settings.retries, 3);
+    assert_eq!(testor.optional.unwrap().retries, 3);
+    assert_eq!(testor.list[0].retries, 3);
+
+    let json = json!({});
+    let res = Testor::validify_from(serde_json::from_value::<TestorPayload>(json).unwrap());
+    let testor = res.unwrap();
+    assert_eq!(testor.settings.name, "");
+    assert_eq!(testor.settings.retries, 0);
+    assert!(testor.optional.is_none());
+    assert!(testor.list.is_empty());
+
+    let json = json!({"settings": {"name": " too long "}, "list": [{"retries": 6}]});
+    let res = Testor::validify_from(serde_json::from_value::<TestorPayload>(json).unwrap());
+    let errs = res.unwrap_err();
+    assert_eq!(errs.errors().len(), 2);
+    assert_eq!(errs.errors()[0].location(), "/settings/name");
+    assert_eq!(errs.errors()[1].location(), "/list/0/retries");
+}
@@ -0,0 +1,72 @@
+use std::marker::PhantomData;
+use validify::{Payload, Validate, Validify, ValidifyPayload};
+
+#[derive(Debug, Validate)]
+#[validate(rules)]
+struct Typed<T> {
+    #[validate(length(min = 1))]
+    name: String,
+    marker: PhantomData<T>,
+    #[allow(dead_code)]
+    unit: (),
+}
+
+#[test]
+fn ignores_phantom_and_unit_fields() {
+    let typed = Typed::<u64> {
+        name: "id".to_string(),
+        marker: PhantomData,
+        unit: (),
+    };
+    assert!(typed.validate().is_ok());
+
+    let typed = Typed::<String> {
+        name: String::new(),
+        marker: PhantomData,
+        unit: (),
+    };
+    let err = typed.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(err.field_errors()[0].location(), "/name");
+
+    let rules = Typed::<u64>::rules();
+    assert_eq!(rules.len(), 1);
+    assert!(rules.contains_key("name"));
+}
+
+#[derive(Debug, Validify)]
+#[validify(builder)]
+struct TypedBuilder<T> {
+    #[modify(trim)]
+    #[validate(length(min = 1))]
+    name: String,
+    marker: PhantomData<T>,
+}
+
+#[test]
+fn builder_defaults_phantom_fields() {
+    let built = TypedBuilder::<u8>::builder().name(" id ").build().unwrap();
+    assert_eq!(built.name, "id");
+}
+
+#[derive(Debug, Validify, Payload)]
+struct Marked {
+    #[modify(trim)]
+    #[validate(length(min = 1))]
+    name: String,
+    marker: PhantomData<fn() -> u8>,
+    unit: (),
+}
+
+#[test]
+fn phantom_fields_are_not_part_of_the_payload() {
+    let payload: MarkedPayload = serde_json::from_value(serde_json::json!({ "name": " id " }))
+        .expect("zero sized fields are not required");
+    let marked = Marked::validify_from(payload).unwrap();
+    assert_eq!(marked.name, "id");
+
+    let payload: MarkedPayload =
+        serde_json::from_value(serde_json::json!({ "name": " " })).unwrap();
+    let err = Marked::validify_from(payload).unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+}
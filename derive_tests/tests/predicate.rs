@@ -0,0 +1,50 @@
+use validify::Validate;
+
+fn starts_before_end(range: &Span) -> bool {
+    range.start < range.end
+}
+
+fn is_short(range: &Span) -> bool {
+    range.end.abs_diff(range.start) <= 10
+}
+
+#[derive(Debug, Validate)]
+#[validate(predicate(function = "starts_before_end", message = "start must be before end"))]
+#[validate(predicate(function = is_short, code = "too_long"))]
+struct Span {
+    start: u64,
+    end: u64,
+}
+
+#[test]
+fn passes_when_predicates_hold() {
+    let span = Span { start: 1, end: 5 };
+    assert!(span.validate().is_ok());
+}
+
+#[test]
+fn failing_predicate_adds_schema_error() {
+    let span = Span { start: 5, end: 1 };
+    let errs = span.validate().unwrap_err();
+    let errs = errs.schema_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "predicate");
+    assert_eq!(
+        errs[0].message(),
+        Some("start must be before end".to_string())
+    );
+}
+
+#[test]
+fn every_failing_predicate_adds_an_error() {
+    let span = Span { start: 30, end: 1 };
+    let errs = span.validate().unwrap_err();
+    assert_eq!(errs.schema_errors().len(), 2);
+
+    let span = Span { start: 1, end: 20 };
+    let errs = span.validate().unwrap_err();
+    let errs = errs.schema_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "too_long");
+    assert_eq!(errs[0].message(), None);
+}
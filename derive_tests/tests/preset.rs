@@ -0,0 +1,101 @@
+use validify::{Validate, Validify};
+
+mod presets {
+    validify::preset!(pub(crate) username {
+        modify(trim),
+        validate(length(min = 1, max = 50), non_control_char)
+    });
+
+    validify::preset!(pub(crate) lowercase { modify(lowercase) });
+
+    validify::preset!(pub(crate) short { validate(length(max = 3)) });
+}
+
+#[derive(Debug, Validify)]
+struct Signup {
+    #[validate(preset = presets::username)]
+    username: String,
+    #[validate(preset = presets::username, preset = presets::lowercase, email)]
+    email: String,
+    #[validate(preset = "presets::username")]
+    nickname: Option<String>,
+}
+
+mod profile {
+    use validify::Validify;
+
+    #[derive(Debug, Validify)]
+    pub struct Profile {
+        #[validate(preset = crate::presets::username)]
+        pub display_name: String,
+        #[validate(email, preset = crate::presets::short, url)]
+        pub link: String,
+    }
+}
+
+#[test]
+fn applies_preset_validators() {
+    let mut signup = Signup {
+        username: "   ".to_string(),
+        email: "not an email".to_string(),
+        nickname: Some("a".repeat(51)),
+    };
+
+    let err = signup.validify().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].location(), "/username");
+    assert_eq!(errs[1].code(), "email");
+    assert_eq!(errs[1].location(), "/email");
+    assert_eq!(errs[2].code(), "length");
+    assert_eq!(errs[2].location(), "/nickname");
+}
+
+#[test]
+fn applies_preset_modifiers() {
+    let mut signup = Signup {
+        username: "  Bob  ".to_string(),
+        email: "  BOB@bob.com ".to_string(),
+        nickname: None,
+    };
+
+    assert!(signup.validify().is_ok());
+    assert_eq!(signup.username, "Bob");
+    assert_eq!(signup.email, "bob@bob.com");
+}
+
+#[test]
+fn shares_presets_between_structs() {
+    let mut profile = profile::Profile {
+        display_name: "  Bob  ".to_string(),
+        link: "long".to_string(),
+    };
+
+    let err = profile.validify().unwrap_err();
+    assert_eq!(profile.display_name, "Bob");
+
+    // Preset validators run in the position they are declared in
+    let codes: Vec<_> = err.errors().iter().map(|err| err.code()).collect();
+    assert_eq!(codes, ["email", "length", "url"]);
+}
+
+#[test]
+fn presets_work_with_validate() {
+    #[derive(Debug, Validate)]
+    struct Short {
+        #[validate(preset = presets::short)]
+        a: String,
+        #[validate(preset = presets::short)]
+        b: Vec<u8>,
+    }
+
+    let short = Short {
+        a: "abcd".to_string(),
+        b: vec![1, 2],
+    };
+
+    let err = short.validate().unwrap_err();
+    assert_eq!(err.field_errors().len(), 1);
+    assert_eq!(err.field_errors()[0].location(), "/a");
+}
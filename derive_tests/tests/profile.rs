@@ -0,0 +1,46 @@
+use std::{cell::RefCell, time::Duration};
+use validify::{Validate, ValidationError};
+
+thread_local! {
+    static TIMINGS: RefCell<Vec<(&'static str, Duration)>> = const { RefCell::new(vec![]) };
+}
+
+fn record(field: &'static str, elapsed: Duration) {
+    TIMINGS.with(|timings| timings.borrow_mut().push((field, elapsed)));
+}
+
+fn slow(_: &str) -> Result<(), ValidationError> {
+    std::thread::sleep(Duration::from_millis(5));
+    Ok(())
+}
+
+#[derive(Debug, Validate)]
+#[validate(profile = "record")]
+struct Profiled {
+    #[validate(length(min = 1))]
+    name: String,
+    #[validate(custom(slow))]
+    bio: String,
+    #[validate(email)]
+    email: Option<String>,
+    unvalidated: u32,
+}
+
+#[test]
+fn profile_callback_is_invoked_once_per_validated_field() {
+    let profiled = Profiled {
+        name: String::new(),
+        bio: "bio".to_string(),
+        email: None,
+        unvalidated: 1,
+    };
+
+    let err = profiled.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(profiled.unvalidated, 1);
+
+    let timings = TIMINGS.with(|timings| timings.take());
+    let fields: Vec<_> = timings.iter().map(|(field, _)| *field).collect();
+    assert_eq!(fields, ["name", "bio", "email"]);
+    assert!(timings[1].1 >= Duration::from_millis(5));
+}
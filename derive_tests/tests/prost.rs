@@ -1,5 +1,6 @@
 use prost::Message;
 
+#[allow(dead_code)]
 #[derive(Message, validify::Validify)]
 struct Data {
     #[validate(range(min = 5.0, max = 10.0))]
@@ -6,7 +6,7 @@ const MIN_CONST: usize = 0;
 // Loose floating point comparison using EPSILON error bound
 macro_rules! assert_float {
     ($e1:expr, $e2:expr) => {
-        assert!(($e2 - $e1).abs() < std::f64::EPSILON);
+        assert!(($e2 - $e1).abs() < f64::EPSILON);
     };
 }
 
@@ -153,3 +153,206 @@ fn can_pass_reference_as_validate() {
     let val = TestStruct { num_field: 1.01 };
     assert!(validate(val).is_err());
 }
+
+#[test]
+fn can_specify_message_per_bound_for_range() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = 0, min_message = "too small", max = 100, max_message = "too big"))]
+        val: i32,
+    }
+
+    let err = TestStruct { val: -1 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].clone().message().unwrap(), "too small");
+
+    let err = TestStruct { val: 101 }.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].clone().message().unwrap(), "too big");
+
+    assert!(TestStruct { val: 50 }.validate().is_ok());
+}
+
+#[test]
+fn general_message_is_fallback_for_range_bounds() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = 0, max = 100, max_message = "too big", message = "out of range"))]
+        val: i32,
+    }
+
+    let err = TestStruct { val: -1 }.validate().unwrap_err();
+    assert_eq!(
+        err.field_errors()[0].clone().message().unwrap(),
+        "out of range"
+    );
+
+    let err = TestStruct { val: 101 }.validate().unwrap_err();
+    assert_eq!(err.field_errors()[0].clone().message().unwrap(), "too big");
+}
+
+#[test]
+fn can_validate_range_step() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = 0, max = 100, step = 5))]
+        val: u32,
+        #[validate(range(min = 1, step = 0.5))]
+        offset: Option<f64>,
+    }
+
+    assert!(TestStruct {
+        val: 10,
+        offset: Some(2.5)
+    }
+    .validate()
+    .is_ok());
+
+    let err = TestStruct {
+        val: 7,
+        offset: Some(2.25),
+    }
+    .validate()
+    .unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "range");
+    assert_eq!(errs[0].location(), "/val");
+    assert_eq!(errs[0].params()["step"], 5.);
+    assert_eq!(errs[0].params()["actual"], 7);
+    assert_eq!(errs[1].location(), "/offset");
+
+    // Bounds still apply to values on a step
+    let err = TestStruct {
+        val: 105,
+        offset: None,
+    }
+    .validate()
+    .unwrap_err();
+    assert_eq!(err.field_errors()[0].params()["max"], 100.);
+}
+
+#[test]
+fn range_rejects_nan_and_infinity() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = 0., max = 1.))]
+        bounded: f64,
+        #[validate(range(min = 0.))]
+        min_only: f64,
+    }
+
+    for value in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let err = TestStruct {
+            bounded: value,
+            min_only: value,
+        }
+        .validate()
+        .unwrap_err();
+        let errs = err.field_errors();
+        assert_eq!(errs.len(), 2, "{value} passed range");
+        assert!(errs.iter().all(|err| err.code() == "range"));
+    }
+
+    let test = TestStruct {
+        bounded: 0.5,
+        min_only: 1e300,
+    };
+    assert!(test.validate().is_ok());
+}
+
+#[test]
+fn finite_rejects_nan_and_infinity() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(finite)]
+        value: f32,
+        #[validate(finite(code = "not_finite"))]
+        optional: Option<f64>,
+    }
+
+    let err = TestStruct {
+        value: f32::NAN,
+        optional: Some(f64::INFINITY),
+    }
+    .validate()
+    .unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "finite");
+    assert_eq!(errs[0].location(), "/value");
+    assert_eq!(errs[1].code(), "not_finite");
+
+    let test = TestStruct {
+        value: 1.5,
+        optional: None,
+    };
+    assert!(test.validate().is_ok());
+}
+
+#[test]
+fn range_resolves_now_at_validation_time() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = "now"))]
+        expires_at: i64,
+        #[validate(range(min = "now-3600", max = "now + 60"))]
+        refreshed_at: u64,
+    }
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let s = TestStruct {
+        expires_at: now as i64 + 600,
+        refreshed_at: now - 1800,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        expires_at: now as i64 - 600,
+        refreshed_at: now - 7200,
+    };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "range");
+    assert_eq!(errs[0].location(), "/expires_at");
+    assert_eq!(errs[1].location(), "/refreshed_at");
+}
+
+#[test]
+fn validates_char_range() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range(min = 'A', max = 'F', max_message = "Grade too low"))]
+        grade: char,
+        #[validate(range(min = 'a'))]
+        initial: Option<char>,
+    }
+
+    let s = TestStruct {
+        grade: 'C',
+        initial: Some('j'),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        grade: 'Z',
+        initial: Some('J'),
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "range");
+    assert_eq!(errs[0].message().as_deref(), Some("Grade too low"));
+    assert_eq!(errs[0].params()["min"], "A");
+    assert_eq!(errs[0].params()["max"], "F");
+    assert_eq!(errs[0].params()["actual"], "Z");
+    assert_eq!(errs[1].location(), "/initial");
+    assert_eq!(errs[1].params()["min"], "a");
+}
@@ -0,0 +1,85 @@
+use std::ops::{Range, RangeInclusive};
+use validify::Validate;
+
+#[test]
+fn validates_range_bounds() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(valid_range, range_len(max = 1000))]
+        pages: Range<u32>,
+        #[validate(valid_range(code = "BAD", message = "NOT_GOOD"))]
+        offset: Option<RangeInclusive<i64>>,
+    }
+
+    let s = TestStruct {
+        pages: 0..1000,
+        offset: Some(-5..=-5),
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        pages: 10..1000,
+        offset: None,
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn errors_inverted_range() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(valid_range)]
+        pages: Range<u32>,
+        #[validate(valid_range(code = "BAD", message = "NOT_GOOD"))]
+        offset: Option<RangeInclusive<i64>>,
+    }
+
+    #[allow(clippy::reversed_empty_ranges)]
+    let s = TestStruct {
+        pages: 10..2,
+        offset: Some(3..=-3),
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "valid_range");
+    assert_eq!(errs[0].location(), "/pages");
+    assert_eq!(errs[0].params()["actual"]["start"], 10);
+    assert_eq!(errs[0].params()["actual"]["end"], 2);
+    assert_eq!(errs[1].code(), "BAD");
+    assert!(matches!(errs[1].message(), Some(val) if val == "NOT_GOOD"));
+}
+
+#[test]
+fn errors_oversized_span() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(range_len(min = 1, max = 1000))]
+        pages: Range<u32>,
+        #[validate(iter(range_len(max = 10)))]
+        windows: Vec<RangeInclusive<u8>>,
+    }
+
+    let s = TestStruct {
+        pages: 0..1001,
+        windows: vec![0..=9, 0..=10],
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "range_len");
+    assert_eq!(errs[0].params()["actual"], 1001);
+    assert_eq!(errs[0].params()["max"], 1000);
+    assert_eq!(errs[1].code(), "range_len");
+    assert_eq!(errs[1].location(), "/windows/1");
+    assert_eq!(errs[1].params()["actual"], 11);
+
+    // Empty and inverted ranges have no values
+    #[allow(clippy::reversed_empty_ranges)]
+    let s = TestStruct {
+        pages: 5..3,
+        windows: vec![],
+    };
+    let err = s.validate().unwrap_err();
+    assert_eq!(err.field_errors()[0].params()["actual"], 0);
+}
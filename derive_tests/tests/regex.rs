@@ -76,3 +76,144 @@ fn can_specify_message_for_regex() {
     assert_eq!(errs.len(), 1);
     assert_eq!(errs[0].clone().message().unwrap(), "oops");
 }
+
+lazy_static! {
+    static ref RE_EXACT: Regex = Regex::new(r"^hello$").unwrap();
+    static ref RE_PREFIX: Regex = Regex::new(r"^hello").unwrap();
+    static ref RE_COMPLEX: Regex = Regex::new(r"^[a-z]+\d{2}$").unwrap();
+}
+
+#[test]
+fn literal_patterns_match_compiled_regexes() {
+    #[derive(Debug, Validate)]
+    struct Optimized {
+        #[validate(regex("^hello$"))]
+        exact: String,
+        #[validate(regex(pattern = "^hello"))]
+        prefix: String,
+        #[validate(regex(pattern = r"^[a-z]+\d{2}$"))]
+        complex: String,
+    }
+
+    #[derive(Debug, Validate)]
+    struct Compiled {
+        #[validate(regex(RE_EXACT))]
+        exact: String,
+        #[validate(regex(RE_PREFIX))]
+        prefix: String,
+        #[validate(regex(RE_COMPLEX))]
+        complex: String,
+    }
+
+    let inputs = [
+        "hello",
+        "hello world",
+        "hell",
+        " hello",
+        "abc12",
+        "",
+        "HELLO",
+    ];
+
+    for input in inputs {
+        let optimized = Optimized {
+            exact: input.to_string(),
+            prefix: input.to_string(),
+            complex: input.to_string(),
+        }
+        .validate();
+
+        let compiled = Compiled {
+            exact: input.to_string(),
+            prefix: input.to_string(),
+            complex: input.to_string(),
+        }
+        .validate();
+
+        assert_eq!(optimized, compiled, "mismatch for input {input:?}");
+    }
+}
+
+#[test]
+fn can_validate_regex_pattern_in_collections_and_options() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(iter(regex("^id-")))]
+        ids: Vec<String>,
+        #[validate(regex(pattern = "^[a-z]{2}$", code = "two_letters"))]
+        opt: Option<String>,
+    }
+
+    let s = TestStruct {
+        ids: vec!["id-1".to_string(), "2".to_string()],
+        opt: Some("abc".to_string()),
+    };
+
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "regex");
+    assert_eq!(errs[0].location(), "/ids/1");
+    assert_eq!(errs[0].params()["actual"], "2");
+    assert_eq!(errs[1].code(), "two_letters");
+
+    let s = TestStruct {
+        ids: vec![],
+        opt: None,
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn validates_each_line_against_regex() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(each_line(regex("^[a-z]+=[0-9]+$")))]
+        entries: String,
+    }
+
+    let s = TestStruct {
+        entries: "a=1\nb=two\nc=3".to_string(),
+    };
+
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "each_line");
+    assert_eq!(errs[0].location(), "/entries");
+    assert_eq!(errs[0].params()["line"], 2);
+    assert_eq!(errs[0].params()["actual"], "b=two");
+
+    let s = TestStruct {
+        entries: "a=1\nb=2\nc=3".to_string(),
+    };
+    assert!(s.validate().is_ok());
+}
+
+#[test]
+fn regex_matches_transformed_copy_of_value() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(regex(pattern = "^[a-z]+$"))]
+        plain: String,
+        #[validate(regex(pattern = "^[a-z]+$", lowercase_first))]
+        lowercased: String,
+        #[validate(regex(pattern = "^[a-z]+$", lowercase_first, trim_first))]
+        trimmed: String,
+    }
+
+    let s = TestStruct {
+        plain: "ABC".to_string(),
+        lowercased: "ABC".to_string(),
+        trimmed: "  ABC ".to_string(),
+    };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].location(), "/plain");
+    assert_eq!(errs[0].params()["actual"], "ABC");
+
+    // The fields themselves are not modified
+    assert_eq!(s.lowercased, "ABC");
+    assert_eq!(s.trimmed, "  ABC ");
+}
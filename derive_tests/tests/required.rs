@@ -94,3 +94,64 @@ fn can_specify_message_for_required() {
     assert_eq!(errs.len(), 1);
     assert_eq!(errs[0].clone().message().unwrap(), "oops");
 }
+
+#[test]
+fn can_specify_distinct_codes_for_required_fields() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(required(code = "email_required"))]
+        email: Option<String>,
+        #[validate(required(code = "name_required"))]
+        name: Option<String>,
+    }
+    let s = TestStruct {
+        email: None,
+        name: None,
+    };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "email_required");
+    assert_eq!(errs[0].field_name().unwrap(), "email");
+    assert_eq!(errs[1].code(), "name_required");
+    assert_eq!(errs[1].field_name().unwrap(), "name");
+}
+
+#[test]
+fn requires_dependents_when_present() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(requires(["billing_zip", "billing_city"]))]
+        billing_street: Option<String>,
+        billing_zip: Option<String>,
+        billing_city: Option<String>,
+    }
+
+    let s = TestStruct {
+        billing_street: None,
+        billing_zip: None,
+        billing_city: None,
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        billing_street: Some("Main Street 1".to_string()),
+        billing_zip: Some("10000".to_string()),
+        billing_city: None,
+    };
+    let errs = s.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "required");
+    assert_eq!(errs[0].location(), "/billing_city");
+    assert_eq!(errs[0].params()["required_by"], "billing_street");
+
+    let s = TestStruct {
+        billing_street: Some("Main Street 1".to_string()),
+        billing_zip: Some("10000".to_string()),
+        billing_city: Some("Zagreb".to_string()),
+    };
+    assert!(s.validate().is_ok());
+}
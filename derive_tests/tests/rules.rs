@@ -0,0 +1,99 @@
+use serde::Deserialize;
+use serde_json::json;
+use validify::{RuleInfo, RuleKind, Validify};
+
+#[test]
+fn lists_rules_per_field() {
+    #[derive(Debug, Deserialize, Validify)]
+    #[validate(rules)]
+    #[allow(dead_code)]
+    #[serde(rename_all = "camelCase")]
+    struct Signup {
+        #[modify(trim, lowercase)]
+        #[validate(email, length(max = 64, code = "email_length"))]
+        email_address: String,
+        #[validate(length(min = 8))]
+        password: String,
+        #[validate(iter(range(min = 1, max = 5)))]
+        ratings: Vec<u8>,
+        nickname: Option<String>,
+    }
+
+    let rules = Signup::rules();
+    assert_eq!(rules.len(), 4);
+
+    let email = &rules["emailAddress"];
+    assert_eq!(email.len(), 4);
+    assert_eq!(
+        email[0],
+        RuleInfo::validator("email").with_param("code", &"email")
+    );
+    assert_eq!(email[1].name, "length");
+    assert_eq!(email[1].params["max"], 64);
+    assert_eq!(email[1].params["code"], "email_length");
+    assert!(!email[1].params.contains_key("min"));
+    assert_eq!(email[2].kind, RuleKind::Modifier);
+    assert_eq!(email[2].name, "trim");
+    assert_eq!(email[3].name, "lowercase");
+
+    let password = &rules["password"];
+    assert_eq!(password.len(), 1);
+    assert_eq!(password[0].kind, RuleKind::Validator);
+    assert_eq!(password[0].name, "length");
+    assert_eq!(password[0].params["min"], 8);
+
+    let ratings = &rules["ratings"];
+    assert_eq!(ratings[0].name, "range");
+    assert_eq!(ratings[0].params["iter"], true);
+    assert_eq!(ratings[0].params["max"], 5.);
+
+    assert!(rules["nickname"].is_empty());
+
+    assert_eq!(
+        serde_json::to_value(&password[0]).unwrap(),
+        json!({ "kind": "validator", "name": "length", "params": { "min": 8, "code": "length" } })
+    );
+}
+
+const ALLOWED: &[u32] = &[2, 4];
+
+fn not_zero(n: &u32) -> Result<(), validify::ValidationError> {
+    if *n != 0 {
+        Ok(())
+    } else {
+        Err(validify::ValidationError::new_field("zero"))
+    }
+}
+
+#[test]
+fn describes_paths_and_literals() {
+    #[derive(Debug, validify::Validate)]
+    #[validate(rules)]
+    struct Described {
+        #[validate(contains("a"), regex("^a"))]
+        a: String,
+        #[validate(one_of(["x", "y"], case_insensitive), ip(format = "v4"))]
+        b: String,
+        #[validate(custom(not_zero), range(max = 10, step = 2), is_in(ALLOWED))]
+        c: u32,
+        #[validate(must_match(a))]
+        d: String,
+    }
+
+    let rules = Described::rules();
+
+    assert_eq!(rules["a"][0].params["value"], "a");
+    assert_eq!(rules["a"][1].params["pattern"], "^a");
+
+    assert_eq!(rules["b"][0].params["values"], json!(["x", "y"]));
+    assert_eq!(rules["b"][0].params["case_insensitive"], true);
+    assert_eq!(rules["b"][1].params["format"], "v4");
+
+    assert_eq!(rules["c"][0].params["function"], "not_zero");
+    assert!(!rules["c"][0].params.contains_key("code"));
+    assert_eq!(rules["c"][1].params["step"], 2.);
+    assert_eq!(rules["c"][2].name, "is_in");
+    assert_eq!(rules["c"][2].params["collection"], "ALLOWED");
+
+    assert_eq!(rules["d"][0].params["value"], "a");
+}
@@ -20,6 +20,7 @@ fn can_validate_schema_fn_ok() {
     assert!(s.validate().is_ok());
 }
 
+#[allow(dead_code)]
 mod some_defining_mod {
     use validify::Validate;
 
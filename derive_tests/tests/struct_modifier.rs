@@ -0,0 +1,146 @@
+use validify::{Modify, Validify};
+
+#[derive(Debug, Clone, Validify)]
+#[modify(custom(flag_if_changed))]
+struct Profile {
+    #[modify(trim, lowercase)]
+    #[validate(length(min = 1))]
+    username: String,
+    changed: bool,
+    #[validify]
+    address: Address,
+}
+
+#[derive(Debug, Clone, Validify)]
+struct Address {
+    #[modify(trim)]
+    street: String,
+}
+
+fn flag_if_changed(profile: &mut Profile, original: &Profile) {
+    profile.changed =
+        profile.username != original.username || profile.address.street != original.address.street;
+}
+
+#[test]
+fn struct_modifier_receives_original() {
+    let mut profile = Profile {
+        username: "  Bob ".to_string(),
+        changed: false,
+        address: Address {
+            street: "Main".to_string(),
+        },
+    };
+    assert!(profile.validify().is_ok());
+    assert_eq!(profile.username, "bob");
+    assert!(profile.changed);
+
+    let mut profile = Profile {
+        username: "bob".to_string(),
+        changed: false,
+        address: Address {
+            street: "Main".to_string(),
+        },
+    };
+    assert!(profile.validify().is_ok());
+    assert!(!profile.changed);
+}
+
+#[test]
+fn struct_modifier_original_predates_nested_modifiers() {
+    let mut profile = Profile {
+        username: "bob".to_string(),
+        changed: false,
+        address: Address {
+            street: " Main ".to_string(),
+        },
+    };
+    assert!(profile.validify().is_ok());
+    assert_eq!(profile.address.street, "Main");
+    assert!(profile.changed);
+}
+
+#[test]
+fn struct_modifier_runs_on_modify() {
+    let mut profile = Profile {
+        username: "BOB".to_string(),
+        changed: false,
+        address: Address {
+            street: "Main".to_string(),
+        },
+    };
+    profile.modify();
+    assert!(profile.changed);
+}
+
+#[derive(Debug, Clone, Validify)]
+#[modify(custom(keep_original_when_empty))]
+struct Generic<T: Clone + Default + PartialEq> {
+    value: T,
+    #[modify(trim)]
+    label: String,
+}
+
+fn keep_original_when_empty<T: Clone + Default + PartialEq>(
+    this: &mut Generic<T>,
+    original: &Generic<T>,
+) {
+    if this.label.is_empty() {
+        this.label = original.label.clone();
+    }
+}
+
+#[test]
+fn struct_modifier_works_with_generics() {
+    let mut g = Generic {
+        value: 1,
+        label: "   ".to_string(),
+    };
+    assert!(g.validify().is_ok());
+    assert_eq!(g.value, 1);
+    assert_eq!(g.label, "   ");
+}
+
+fn compute_checksum(order: &mut Order) {
+    order.checksum = Some(format!("{}:{}", order.item, order.quantity));
+}
+
+#[derive(Debug, Validify)]
+#[validify(after_validate = "compute_checksum")]
+struct Order {
+    #[modify(trim)]
+    #[validate(length(min = 1))]
+    item: String,
+    #[validate(range(min = 1.))]
+    quantity: u32,
+    checksum: Option<String>,
+}
+
+#[test]
+fn after_validate_runs_only_when_valid() {
+    let mut order = Order {
+        item: " apple ".to_string(),
+        quantity: 2,
+        checksum: None,
+    };
+    assert!(order.validify().is_ok());
+    assert_eq!(order.checksum.as_deref(), Some("apple:2"));
+
+    let mut order = Order {
+        item: "apple".to_string(),
+        quantity: 0,
+        checksum: None,
+    };
+    assert!(order.validify().is_err());
+    assert_eq!(order.checksum, None);
+
+    // Plain modification does not validate, so the hook does not run
+    let mut order = Order {
+        item: " pear ".to_string(),
+        quantity: 1,
+        checksum: None,
+    };
+    order.modify();
+    assert_eq!(order.item, "pear");
+    assert_eq!(order.checksum, None);
+}
@@ -0,0 +1,60 @@
+use validify::Validate;
+
+#[derive(Debug, Validate)]
+#[validate(tag = "kind")]
+struct Payment {
+    kind: String,
+    #[validate(variants = ["card"], required, length(min = 12, max = 19))]
+    card_number: Option<String>,
+    #[validate(variants = ["transfer", "direct_debit"], required)]
+    iban: Option<String>,
+    #[validate(range(min = 1.))]
+    amount: u64,
+}
+
+#[test]
+fn validates_fields_of_the_tagged_variant() {
+    let payment = Payment {
+        kind: "card".to_string(),
+        card_number: Some("4242424242424242".to_string()),
+        iban: None,
+        amount: 10,
+    };
+    assert!(payment.validate().is_ok());
+
+    let payment = Payment {
+        kind: "transfer".to_string(),
+        card_number: None,
+        iban: Some("HR1210010051863000160".to_string()),
+        amount: 10,
+    };
+    assert!(payment.validate().is_ok());
+}
+
+#[test]
+fn fails_on_missing_fields_of_the_tagged_variant() {
+    let payment = Payment {
+        kind: "card".to_string(),
+        card_number: Some("4242".to_string()),
+        iban: None,
+        amount: 0,
+    };
+    let errs = payment.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "length");
+    assert_eq!(errs[0].location(), "/card_number");
+    assert_eq!(errs[1].location(), "/amount");
+
+    let payment = Payment {
+        kind: "direct_debit".to_string(),
+        card_number: Some("4242".to_string()),
+        iban: None,
+        amount: 10,
+    };
+    let errs = payment.validate().unwrap_err();
+    let errs = errs.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "required");
+    assert_eq!(errs[0].location(), "/iban");
+}
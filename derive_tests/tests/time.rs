@@ -798,3 +798,72 @@ fn with_option_and_duration() {
         "2600-04-20T12:00:00"
     );
 }
+
+#[test]
+fn future_and_past() {
+    use chrono::{DateTime, Duration, FixedOffset, Utc};
+
+    #[derive(Debug, Validate)]
+    struct Testor {
+        #[validate(future)]
+        starts: DateTime<Utc>,
+        #[validate(past(code = "not_past"))]
+        created: DateTime<FixedOffset>,
+        #[validate(future(grace = "5m"))]
+        deadline: Option<DateTime<Utc>>,
+    }
+
+    let now = Utc::now();
+
+    let test = Testor {
+        starts: now + Duration::hours(1),
+        created: (now - Duration::hours(1)).fixed_offset(),
+        deadline: Some(now - Duration::minutes(1)),
+    };
+    assert!(test.validate().is_ok());
+
+    let test = Testor {
+        starts: now - Duration::days(1),
+        created: (now + Duration::hours(1)).fixed_offset(),
+        deadline: Some(now - Duration::minutes(10)),
+    };
+    let err = test.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 3);
+
+    assert_eq!(errs[0].code(), "future");
+    assert_eq!(errs[0].location(), "/starts");
+    assert_eq!(
+        errs[0].params()["actual"],
+        to_value(test.starts.to_rfc3339()).unwrap()
+    );
+    let target =
+        DateTime::parse_from_rfc3339(errs[0].params()["target"].as_str().unwrap()).unwrap();
+    assert!(target >= now);
+    assert_eq!(errs[0].params()["grace"], 0);
+
+    assert_eq!(errs[1].code(), "not_past");
+    assert_eq!(errs[2].code(), "future");
+    assert_eq!(errs[2].params()["grace"], 300);
+}
+
+#[test]
+fn grace_beyond_representable_dates_accepts_all() {
+    use chrono::{DateTime, Duration, Utc};
+
+    #[derive(Debug, Validate)]
+    struct Testor {
+        #[validate(future(grace = "100000000w"))]
+        starts: DateTime<Utc>,
+        #[validate(past(grace = "100000000w"))]
+        created: DateTime<Utc>,
+    }
+
+    let now = Utc::now();
+
+    let test = Testor {
+        starts: now - Duration::days(365),
+        created: now + Duration::days(365),
+    };
+    assert!(test.validate().is_ok());
+}
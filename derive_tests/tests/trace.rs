@@ -0,0 +1,90 @@
+use std::{
+    io,
+    sync::{Arc, Mutex},
+};
+use validify::Validify;
+
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<u8>>>);
+
+impl io::Write for Capture {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn traces_changed_fields() {
+    #[derive(Debug, serde::Deserialize, Validify)]
+    #[validify(trace_modifications)]
+    struct Testor {
+        #[modify(trim, uppercase)]
+        name: String,
+        #[modify(lowercase)]
+        tag: Option<String>,
+    }
+
+    let capture = Capture::default();
+    let writer = capture.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let mut testor = Testor {
+        name: "  hello ".to_string(),
+        tag: Some("tag".to_string()),
+    };
+    tracing::subscriber::with_default(subscriber, || testor.validify().unwrap());
+
+    assert_eq!(testor.name, "HELLO");
+
+    let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+    let events: Vec<_> = output.lines().collect();
+    assert_eq!(events.len(), 2);
+    assert!(events[0].contains("field modified"));
+    assert!(events[0].contains("field=\"name\" modifier=\"trim\""));
+    assert!(events[0].contains(r#"before="  hello " after="hello""#));
+    assert!(events[1].contains("modifier=\"uppercase\""));
+    assert!(events[1].contains(r#"before="hello" after="HELLO""#));
+}
+
+#[test]
+fn profiles_validated_fields() {
+    #[derive(Debug, validify::Validate)]
+    #[validate(profile)]
+    struct Testor {
+        #[validate(length(min = 1))]
+        name: String,
+        untouched: String,
+    }
+
+    let capture = Capture::default();
+    let writer = capture.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::DEBUG)
+        .with_ansi(false)
+        .with_writer(move || writer.clone())
+        .finish();
+
+    let testor = Testor {
+        name: "name".to_string(),
+        untouched: String::new(),
+    };
+    tracing::subscriber::with_default(subscriber, || {
+        validify::Validate::validate(&testor).unwrap()
+    });
+    assert!(testor.untouched.is_empty());
+
+    let output = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+    let events: Vec<_> = output.lines().collect();
+    assert_eq!(events.len(), 1);
+    assert!(events[0].contains("field validated"));
+    assert!(events[0].contains("field=\"name\""));
+    assert!(events[0].contains("elapsed_us="));
+}
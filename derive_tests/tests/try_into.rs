@@ -0,0 +1,82 @@
+use validify::Validate;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(u8)]
+enum Color {
+    Red = 1,
+    Green = 2,
+    Blue = 3,
+}
+
+impl TryFrom<u8> for Color {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Color::Red),
+            2 => Ok(Color::Green),
+            3 => Ok(Color::Blue),
+            _ => Err(value),
+        }
+    }
+}
+
+#[test]
+fn validates_valid_discriminant() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(try_into(type = "Color"))]
+        color: u8,
+    }
+
+    let s = TestStruct { color: 2 };
+    assert!(s.validate().is_ok());
+    assert_eq!(Color::try_from(s.color), Ok(Color::Green));
+}
+
+#[test]
+fn errors_invalid_discriminant() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(try_into(type = "Color"))]
+        color: u8,
+    }
+
+    let s = TestStruct { color: 7 };
+    let res = s.validate();
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 1);
+    assert_eq!(errs[0].code(), "invalid_variant");
+    assert_eq!(errs[0].params()["actual"], 7);
+}
+
+#[test]
+fn validates_optional_and_iter_discriminants() {
+    #[derive(Debug, Validate)]
+    struct TestStruct {
+        #[validate(try_into(type = "Color", code = "BAD", message = "NOT_GOOD"))]
+        color: Option<u8>,
+        #[validate(iter(try_into(type = "Color")))]
+        palette: Vec<u8>,
+    }
+
+    let s = TestStruct {
+        color: None,
+        palette: vec![1, 3],
+    };
+    assert!(s.validate().is_ok());
+
+    let s = TestStruct {
+        color: Some(0),
+        palette: vec![1, 4],
+    };
+    let err = s.validate().unwrap_err();
+    let errs = err.field_errors();
+    assert_eq!(errs.len(), 2);
+    assert_eq!(errs[0].code(), "BAD");
+    assert!(matches!(errs[0].message(), Some(val) if val == "NOT_GOOD"));
+    assert_eq!(errs[1].code(), "invalid_variant");
+    assert_eq!(errs[1].location(), "/palette/1");
+}
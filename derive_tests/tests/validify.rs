@@ -410,10 +410,7 @@ fn greater_than_now(date: &str) -> Result<(), ValidationError> {
     let parsed = chrono::NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M:%S");
     match parsed {
         Ok(date) => {
-            if date
-                < chrono::NaiveDateTime::from_timestamp_opt(chrono::Utc::now().timestamp(), 0)
-                    .unwrap()
-            {
+            if date < chrono::Utc::now().naive_utc() {
                 Err(ValidationError::new_field("invalid_date"))
             } else {
                 Ok(())
@@ -561,3 +558,80 @@ fn biggest_of_bois() {
     assert_eq!(schema_errs.len(), 2);
     assert_eq!(field_errs.len(), 9);
 }
+
+#[test]
+fn repair_fixes_struct_before_revalidating_once() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static REPAIRS: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Debug, Validify)]
+    #[validify(repair = "fix_invalid")]
+    struct Code {
+        #[validate(length(max = 5))]
+        value: String,
+    }
+
+    fn fix_invalid(code: &mut Code, errors: &ValidationErrors) {
+        REPAIRS.fetch_add(1, Ordering::SeqCst);
+        if errors.errors().iter().any(|err| err.location() == "/value") {
+            code.value = code.value.trim().to_string();
+        }
+    }
+
+    let mut code = Code {
+        value: "  abc  ".to_string(),
+    };
+    code.validify().unwrap();
+    assert_eq!(code.value, "abc");
+    assert_eq!(REPAIRS.load(Ordering::SeqCst), 1);
+
+    let mut code = Code {
+        value: "abc".to_string(),
+    };
+    code.validify().unwrap();
+    assert_eq!(REPAIRS.load(Ordering::SeqCst), 1);
+
+    let mut code = Code {
+        value: " abcdefg ".to_string(),
+    };
+    let err = code.validify().unwrap_err();
+    assert_eq!(err.errors()[0].code(), "length");
+    assert_eq!(code.value, "abcdefg");
+    assert_eq!(REPAIRS.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn tracks_fields_changed_by_modifiers() {
+    #[derive(Debug, Validify)]
+    #[validify(track_changes)]
+    struct Testor {
+        #[modify(trim)]
+        name: String,
+        #[modify(lowercase)]
+        email: String,
+        #[modify(trim)]
+        nickname: Option<String>,
+        #[validate(range(min = 1.))]
+        age: u32,
+    }
+
+    let testor = Testor {
+        name: "  alice  ".to_string(),
+        email: "alice@example.com".to_string(),
+        nickname: Some(" ally".to_string()),
+        age: 30,
+    };
+
+    let (testor, changes) = testor.validate_into_tracked().unwrap();
+    assert_eq!(testor.name, "alice");
+    assert_eq!(changes, vec!["name", "nickname"]);
+
+    let testor = Testor {
+        name: "alice".to_string(),
+        email: "alice@example.com".to_string(),
+        nickname: None,
+        age: 0,
+    };
+    assert!(testor.validate_into_tracked().is_err());
+}
@@ -0,0 +1,101 @@
+use validify::{Validate, Validify};
+
+#[derive(Debug, Validify)]
+struct Listing {
+    #[validate(length(min = 3), warn(length(max = 10, code = "long_title")))]
+    title: String,
+    #[modify(trim)]
+    #[validate(warn(contains("@")))]
+    contact: Option<String>,
+}
+
+#[test]
+fn report_contains_errors_and_warnings() {
+    let listing = Listing {
+        title: "A".to_string(),
+        contact: Some("phone".to_string()),
+    };
+
+    let report = listing.validate_report();
+    assert!(!report.is_acceptable());
+    assert_eq!(report.errors.errors().len(), 1);
+    assert_eq!(report.errors.errors()[0].code(), "length");
+    assert_eq!(report.warnings.errors().len(), 1);
+    assert_eq!(report.warnings.errors()[0].code(), "contains");
+    assert_eq!(report.warnings.errors()[0].location(), "/contact");
+}
+
+#[test]
+fn warnings_alone_are_acceptable() {
+    let mut listing = Listing {
+        title: "A very long title".to_string(),
+        contact: Some(" me@example.com ".to_string()),
+    };
+
+    // Warnings never fail validation
+    assert!(listing.validify().is_ok());
+
+    let report = listing.validate_report();
+    assert!(report.is_acceptable());
+    assert!(report.errors.is_empty());
+    assert_eq!(report.warnings.errors().len(), 1);
+    assert_eq!(report.warnings.errors()[0].code(), "long_title");
+    assert_eq!(report.warnings.errors()[0].location(), "/title");
+
+    let listing = Listing {
+        title: "Fine".to_string(),
+        contact: None,
+    };
+    let report = listing.validate_report();
+    assert!(report.is_acceptable());
+    assert!(report.warnings.is_empty());
+}
+
+#[derive(Debug, Validify)]
+struct Catalog {
+    #[validate(warn(length(max = 5, code = "long_name")))]
+    name: String,
+    #[validify]
+    featured: Listing,
+    #[validate]
+    listings: Vec<Listing>,
+    #[validate]
+    archived: Option<Listing>,
+}
+
+#[test]
+fn collects_warnings_of_nested_structs() {
+    let listing = |title: &str, contact: &str| Listing {
+        title: title.to_string(),
+        contact: Some(contact.to_string()),
+    };
+
+    let catalog = Catalog {
+        name: "Catalog".to_string(),
+        featured: listing("Featured", "phone"),
+        listings: vec![
+            listing("Fine", "a@b.c"),
+            listing("A very long title", "a@b.c"),
+        ],
+        archived: Some(listing("Archived", "phone")),
+    };
+
+    let report = catalog.validate_report();
+    assert!(report.is_acceptable());
+
+    let warnings: Vec<_> = report
+        .warnings
+        .errors()
+        .iter()
+        .map(|warning| (warning.location().to_string(), warning.code()))
+        .collect();
+    assert_eq!(
+        warnings,
+        [
+            ("/name".to_string(), "long_name".to_string()),
+            ("/featured/contact".to_string(), "contains".to_string()),
+            ("/listings/1/title".to_string(), "long_title".to_string()),
+            ("/archived/contact".to_string(), "contains".to_string()),
+        ]
+    );
+}
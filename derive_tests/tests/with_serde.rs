@@ -319,3 +319,37 @@ fn vec_custom_serde() {
     assert!(res.languages_used.is_empty());
     assert!(res.order_by.is_none());
 }
+
+#[test]
+fn annotates_errors_of_defaulted_fields() {
+    #[derive(Debug, Validate, Deserialize)]
+    struct Test {
+        #[serde(default)]
+        #[validate(length(min = 1), was_defaulted_field = "name_defaulted")]
+        name: String,
+        #[serde(skip)]
+        name_defaulted: bool,
+        #[validate(length(min = 1))]
+        other: String,
+    }
+
+    let json = json!({ "other": "" });
+    let mut test: Test = serde_json::from_value(json.clone()).unwrap();
+    test.name_defaulted = json.get("name").is_none();
+
+    let err = test.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 2);
+    assert_eq!(err.errors()[0].location(), "/name");
+    assert_eq!(err.errors()[0].params()["was_defaulted"], true);
+    assert!(!err.errors()[1].params().contains_key("was_defaulted"));
+
+    let test = Test {
+        name: String::new(),
+        name_defaulted: false,
+        other: "other".to_string(),
+    };
+
+    let err = test.validate().unwrap_err();
+    assert_eq!(err.errors().len(), 1);
+    assert_eq!(err.errors()[0].params()["was_defaulted"], false);
+}
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{to_value, Value};
-use std::{collections::HashMap, fmt::Display};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    hash::{Hash, Hasher},
+};
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub enum ValidationError {
@@ -42,7 +46,7 @@ impl ValidationError {
             field: None,
             code,
             message: None,
-            params: Box::<HashMap<&'static str, Value>>::default(),
+            params: Box::default(),
             location: String::new(),
         }
     }
@@ -53,7 +57,7 @@ impl ValidationError {
             field: Some(field),
             code,
             message: None,
-            params: Box::<HashMap<&'static str, Value>>::default(),
+            params: Box::default(),
             location: String::new(),
         }
     }
@@ -196,6 +200,47 @@ impl ValidationError {
             } => *message = Some(msg),
         }
     }
+
+    pub fn set_code(&mut self, new_code: &'static str) {
+        match self {
+            ValidationError::Schema { ref mut code, .. } => *code = new_code,
+            ValidationError::Field { ref mut code, .. } => *code = new_code,
+        }
+    }
+}
+
+/// Params are hashed by their keys only, in sorted order, since the map's iteration order is arbitrary
+/// and JSON values are not hashable. This is consistent with equality as equal errors have equal keys.
+impl Hash for ValidationError {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            ValidationError::Schema {
+                code,
+                message,
+                location,
+            } => {
+                code.hash(state);
+                message.hash(state);
+                location.hash(state);
+            }
+            ValidationError::Field {
+                field,
+                code,
+                params,
+                message,
+                location,
+            } => {
+                field.hash(state);
+                code.hash(state);
+                let mut keys = params.keys().collect::<Vec<_>>();
+                keys.sort_unstable();
+                keys.hash(state);
+                message.hash(state);
+                location.hash(state);
+            }
+        }
+    }
 }
 
 impl std::error::Error for ValidationError {
@@ -211,52 +256,165 @@ impl std::error::Error for ValidationError {
     }
 }
 
-#[derive(Default, Debug, Serialize, Clone, PartialEq)]
-pub struct ValidationErrors(Vec<ValidationError>);
+/// The code of the schema error added once the maximum number of errors is reached.
+pub const MAX_ERRORS_CODE: &str = "max_errors";
+
+/// The errors of a validation, optionally capped to a maximum number of errors.
+#[derive(Default, Debug, Clone)]
+pub struct ValidationErrors {
+    errors: Vec<ValidationError>,
+    /// Whether errors were dropped because the maximum number of errors was reached
+    truncated: bool,
+    /// The maximum number of errors, not taken into account when comparing errors
+    max_errors: Option<usize>,
+}
+
+/// Serializes as the list of errors only. Truncation shows up as the [MAX_ERRORS_CODE] error.
+impl Serialize for ValidationErrors {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.errors.serialize(serializer)
+    }
+}
+
+impl PartialEq for ValidationErrors {
+    fn eq(&self, other: &Self) -> bool {
+        self.errors == other.errors && self.truncated == other.truncated
+    }
+}
+
+impl Eq for ValidationErrors {}
+
+impl Hash for ValidationErrors {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.errors.hash(state);
+        self.truncated.hash(state);
+    }
+}
 
 impl ValidationErrors {
     pub fn new() -> ValidationErrors {
-        ValidationErrors(Vec::new())
+        ValidationErrors::default()
+    }
+
+    /// Creates errors that stop accumulating once `max` errors are added. The first error past the
+    /// cap is replaced by a schema error with the [MAX_ERRORS_CODE] code marking the errors as
+    /// truncated, any further errors are dropped.
+    pub fn with_max_errors(max: usize) -> ValidationErrors {
+        ValidationErrors {
+            max_errors: Some(max),
+            ..Default::default()
+        }
     }
 
     /// Merge validation errors from the given arg to the calling errors.
     pub fn merge(&mut self, errors: ValidationErrors) {
-        self.0.append(
-            &mut errors
-                .0
-                .into_iter()
-                .filter(|err| !self.0.contains(err))
-                .collect(),
-        )
+        self.truncated |= errors.truncated;
+        let existing = self.errors.len();
+        for err in errors.errors {
+            if self.errors[..existing].contains(&err) {
+                continue;
+            }
+            // Past the cap, the first error only marks the errors as truncated
+            let full = self.is_full();
+            self.add(err);
+            if full {
+                break;
+            }
+        }
     }
 
-    /// Returns a slice of all the errors that ocurred during validation
+    /// Returns a slice of all the errors that occurred during validation
     pub fn errors(&self) -> &[ValidationError] {
-        &self.0
+        &self.errors
     }
 
     pub fn add(&mut self, error: ValidationError) {
-        self.0.push(error)
+        if let Some(max) = self.max_errors.filter(|max| self.errors.len() >= *max) {
+            if !self.truncated {
+                self.truncated = true;
+                let mut marker = ValidationError::new_schema(MAX_ERRORS_CODE);
+                marker.set_message(format!("Validation stopped after {max} errors"));
+                self.errors.push(marker);
+            }
+            return;
+        }
+        self.errors.push(error)
+    }
+
+    /// Returns `true` if errors were dropped because the maximum number of errors was reached.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    fn is_full(&self) -> bool {
+        self.max_errors.is_some_and(|max| self.errors.len() >= max)
+    }
+
+    /// Removes errors with the same location, code and message as an earlier error, regardless of
+    /// their params.
+    pub fn dedup(&mut self) {
+        let mut seen = HashSet::new();
+        self.errors
+            .retain(|err| seen.insert((err.location().to_string(), err.code(), err.message())));
     }
 
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.errors.is_empty()
     }
 
     pub fn errors_mut(&mut self) -> &mut [ValidationError] {
-        &mut self.0
+        &mut self.errors
     }
 
     pub fn field_errors(&self) -> Vec<ValidationError> {
-        self.0
+        self.errors
             .iter()
             .filter(|err| matches!(err, ValidationError::Field { .. }))
             .cloned()
             .collect()
     }
 
+    /// Returns the location and the error that occurred first. With `Validate`, field errors are
+    /// added in the order their fields are declared, followed by any schema errors. With `Validify`,
+    /// the errors of nested `#[validify]` fields come before all the others, since nested structs
+    /// are validified before the modifiers and validations of the struct run.
+    pub fn first_error(&self) -> Option<(String, &ValidationError)> {
+        self.errors
+            .first()
+            .map(|err| (err.location().to_string(), err))
+    }
+
+    /// Returns the dotted paths of the fields with at least one error, e.g. `address.city`, in the
+    /// order their errors were added. Each path is listed once.
+    pub fn failed_fields(&self) -> Vec<String> {
+        let mut seen = HashSet::new();
+        let mut fields = vec![];
+        for err in self.errors.iter() {
+            let ValidationError::Field { location, .. } = err else {
+                continue;
+            };
+            let path = location.trim_start_matches('/').replace('/', ".");
+            if seen.insert(path.clone()) {
+                fields.push(path);
+            }
+        }
+        fields
+    }
+
+    /// Returns every error paired with the dotted path of its location, e.g. `address.city`. Schema
+    /// errors are paired with an empty path.
+    pub fn flatten(&self) -> Vec<(String, ValidationError)> {
+        self.errors
+            .iter()
+            .map(|err| {
+                let path = err.location().trim_start_matches('/').replace('/', ".");
+                (path, err.clone())
+            })
+            .collect()
+    }
+
     pub fn schema_errors(&self) -> Vec<ValidationError> {
-        self.0
+        self.errors
             .iter()
             .filter(|err| matches!(err, ValidationError::Schema { .. }))
             .cloned()
@@ -264,6 +422,21 @@ impl ValidationErrors {
     }
 }
 
+/// The errors and warnings of a validation. Warnings are produced by validators declared in `warn`
+/// and do not make the validated value unacceptable.
+#[derive(Default, Debug, Serialize, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub errors: ValidationErrors,
+    pub warnings: ValidationErrors,
+}
+
+impl ValidationReport {
+    /// Returns `true` if there are no errors, regardless of any warnings.
+    pub fn is_acceptable(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
 impl std::error::Error for ValidationErrors {
     fn description(&self) -> &str {
         "Validation failed"
@@ -281,7 +454,7 @@ impl std::fmt::Display for ValidationError {
                 ref message,
                 ref location,
             } => {
-                let message = message.as_ref().map_or_else(|| "", |f| f);
+                let message = message.as_deref().unwrap_or_default();
                 write!(
                     fmt,
                     "Schema validation error: {{ code: {code} message: {message}, location: {location} }}"
@@ -294,7 +467,7 @@ impl std::fmt::Display for ValidationError {
                 params,
                 location,
             } => {
-                let message = message.as_ref().map_or_else(|| "", |f| f);
+                let message = message.as_deref().unwrap_or_default();
                 write!(
                     fmt,
                     "Validation error: {{ code: {code} location: {location}, field: {}, message: {message}, params: {params:?} }}", field.unwrap()
@@ -312,3 +485,195 @@ impl std::fmt::Display for ValidationErrors {
         Ok(())
     }
 }
+
+/// Compact binary encoding of validation errors for caching the results of expensive validations.
+///
+/// Errors are decoded into owned private types first. Codes, field names and param names are then
+/// resolved against the ones encoded by this process, so they can be handed out as `&'static str`
+/// without leaking the strings of untrusted bytes. Params are stored as JSON since the binary
+/// format cannot describe arbitrary values.
+#[cfg(feature = "postcard")]
+mod cache {
+    use super::{ValidationError, ValidationErrors};
+    use serde::{Deserialize, Serialize};
+    use serde_json::Value;
+    use std::{
+        collections::{HashMap, HashSet},
+        sync::{Mutex, OnceLock},
+    };
+
+    #[derive(Serialize, Deserialize)]
+    struct CachedErrors {
+        errors: Vec<CachedError>,
+        truncated: bool,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    enum CachedError {
+        Schema {
+            code: String,
+            message: Option<String>,
+            location: String,
+        },
+        Field {
+            field: Option<String>,
+            code: String,
+            params: Vec<(String, String)>,
+            message: Option<String>,
+            location: String,
+        },
+    }
+
+    /// The codes, field names and param names of the errors encoded so far. These are already
+    /// `'static`, so remembering them allocates nothing but the set.
+    fn known() -> &'static Mutex<HashSet<&'static str>> {
+        static KNOWN: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+        KNOWN.get_or_init(Default::default)
+    }
+
+    fn resolve(known: &HashSet<&'static str>, s: &str) -> Result<&'static str, postcard::Error> {
+        known.get(s).copied().ok_or(postcard::Error::SerdeDeCustom)
+    }
+
+    impl ValidationErrors {
+        /// Encodes the errors with `postcard`.
+        pub fn to_bytes(&self) -> Result<Vec<u8>, postcard::Error> {
+            let mut known = known().lock().unwrap();
+            let errors: Vec<CachedError> = self
+                .errors
+                .iter()
+                .map(|err| match err {
+                    ValidationError::Schema {
+                        code,
+                        message,
+                        location,
+                    } => {
+                        known.insert(*code);
+                        CachedError::Schema {
+                            code: code.to_string(),
+                            message: message.clone(),
+                            location: location.clone(),
+                        }
+                    }
+                    ValidationError::Field {
+                        field,
+                        code,
+                        params,
+                        message,
+                        location,
+                    } => {
+                        known.extend(field.iter().chain([code]).chain(params.keys()));
+                        CachedError::Field {
+                            field: field.map(str::to_string),
+                            code: code.to_string(),
+                            params: params
+                                .iter()
+                                .map(|(key, value)| (key.to_string(), value.to_string()))
+                                .collect(),
+                            message: message.clone(),
+                            location: location.clone(),
+                        }
+                    }
+                })
+                .collect();
+            postcard::to_allocvec(&CachedErrors {
+                errors,
+                truncated: self.truncated,
+            })
+        }
+
+        /// Decodes errors encoded with [ValidationErrors::to_bytes]. Fails if the errors contain a
+        /// code, field name or param name this process has not encoded yet, e.g. after a restart, in
+        /// which case the cached entry should be treated as missing.
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, postcard::Error> {
+            let cached: CachedErrors = postcard::from_bytes(bytes)?;
+            let known = known().lock().unwrap();
+            let mut errors = Vec::with_capacity(cached.errors.len());
+            for err in cached.errors {
+                errors.push(match err {
+                    CachedError::Schema {
+                        code,
+                        message,
+                        location,
+                    } => ValidationError::Schema {
+                        code: resolve(&known, &code)?,
+                        message,
+                        location,
+                    },
+                    CachedError::Field {
+                        field,
+                        code,
+                        params,
+                        message,
+                        location,
+                    } => {
+                        let mut decoded = HashMap::with_capacity(params.len());
+                        for (key, value) in params {
+                            let value = serde_json::from_str::<Value>(&value)
+                                .map_err(|_| postcard::Error::SerdeDeCustom)?;
+                            decoded.insert(resolve(&known, &key)?, value);
+                        }
+                        ValidationError::Field {
+                            field: field.map(|field| resolve(&known, &field)).transpose()?,
+                            code: resolve(&known, &code)?,
+                            params: Box::new(decoded),
+                            message,
+                            location,
+                        }
+                    }
+                });
+            }
+            Ok(ValidationErrors {
+                errors,
+                truncated: cached.truncated,
+                max_errors: None,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ValidationError, ValidationErrors};
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash<T: Hash>(value: &T) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        value.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn errors(params: &[(&'static str, i32)]) -> ValidationErrors {
+        let mut err = ValidationError::new_field_named("field", "code");
+        for (name, value) in params {
+            err.add_param(name, value);
+        }
+        err.set_location("field");
+
+        let mut errors = ValidationErrors::new();
+        errors.add(err);
+        errors.add(ValidationError::new_schema("schema").with_message("oops".to_string()));
+        errors
+    }
+
+    #[test]
+    fn independently_constructed_errors_are_equal() {
+        let a = errors(&[("min", 1), ("max", 10), ("actual", 11)]);
+        let b = errors(&[("actual", 11), ("max", 10), ("min", 1)]);
+
+        assert_eq!(a, b);
+        assert_eq!(hash(&a), hash(&b));
+    }
+
+    #[test]
+    fn different_errors_are_not_equal() {
+        let a = errors(&[("min", 1)]);
+        let b = errors(&[("min", 2)]);
+        let c = errors(&[("max", 1)]);
+
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(hash(&a), hash(&c));
+    }
+}
@@ -1,34 +1,158 @@
 #![doc = include_str!(concat!("../", std::env!("CARGO_PKG_README")))]
 
 mod error;
+mod modification;
+mod rules;
 pub mod traits;
 mod validation;
 
-pub use error::{ValidationError, ValidationErrors};
+pub use error::{ValidationError, ValidationErrors, ValidationReport, MAX_ERRORS_CODE};
+pub use rules::{RuleInfo, RuleKind};
+#[cfg(feature = "chrono")]
 pub use validation::time;
 
+#[cfg(feature = "hash")]
+pub use modification::hash::{hash_sha256, hash_sha512};
+
+pub use modification::number::{normalize_number, validate_parse_number};
+pub use modification::scheme::ensure_scheme;
+
+#[cfg(feature = "timeout")]
+pub use validation::timeout::validate_with_timeout;
+
 pub use validation::{
     cards::validate_credit_card,
-    contains::validate_contains,
-    email::validate_email,
+    contains::{validate_contains, validate_contains_word},
+    email::{validate_email, validate_email_tld},
     ip::{validate_ip, validate_ip_v4, validate_ip_v6},
-    length::validate_length,
+    length::{validate_length, validate_non_whitespace_length},
     must_match::validate_must_match,
     non_control_char::validate_non_control_character,
+    one_of::validate_one_of,
     phone::validate_phone,
     r#in::validate_in,
-    range::validate_range,
+    range::{validate_range, validate_step},
     required::validate_required,
+    span::{validate_range_len, validate_valid_range},
     urls::validate_url,
 };
 
-pub use validify_derive::{schema_err, schema_validation, Payload, Validate, Validify};
+pub use traits::Discriminants;
+pub use validify_derive::{
+    schema_err, schema_validation, Discriminants, Payload, Validate, Validify,
+};
+
+#[doc(hidden)]
+pub use validify_derive::__resolve_preset;
+
+/// Used by the generated code. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::traits::{AsStr, Contains, HasLen};
+    pub use regex::Regex;
+    use std::sync::OnceLock;
+
+    #[cfg(feature = "rayon")]
+    pub use rayon;
+
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+
+    /// Requires traced fields to be `Debug` regardless of the `tracing` feature, so enabling it never
+    /// breaks a build.
+    pub fn assert_debug<T: ::std::fmt::Debug + ?Sized>(_: &T) {}
+
+    /// Compiles the pattern on first use. Patterns are checked by the derive macro so this never panics.
+    pub fn lazy_regex(cell: &'static OnceLock<Regex>, pattern: &str) -> &'static Regex {
+        cell.get_or_init(|| Regex::new(pattern).unwrap())
+    }
+
+    /// Selects the value itself for the `length` and `contains` validators when it implements
+    /// [HasLen] or [Contains], and [AsStr] for any other `AsRef<str>` type. The fallback impls are on `&Select` so method resolution only
+    /// reaches them through the extra autoref, i.e. `(&Select(value)).has_len()`.
+    pub struct Select<'a, T: ?Sized>(pub &'a T);
+
+    pub trait SelectHasLen<'a, T: ?Sized> {
+        fn has_len(&self) -> &'a T;
+    }
+
+    impl<'a, T: ?Sized> SelectHasLen<'a, T> for Select<'a, T>
+    where
+        &'a T: HasLen,
+    {
+        fn has_len(&self) -> &'a T {
+            self.0
+        }
+    }
+
+    pub trait SelectStrLen<'a, T: ?Sized> {
+        fn has_len(&self) -> AsStr<'a, T>;
+    }
+
+    impl<'a, T: AsRef<str> + ?Sized> SelectStrLen<'a, T> for &Select<'a, T> {
+        fn has_len(&self) -> AsStr<'a, T> {
+            AsStr(self.0)
+        }
+    }
+
+    pub trait SelectContains<'a, T: ?Sized> {
+        fn haystack(&self) -> &'a T;
+    }
+
+    impl<'a, T: ?Sized> SelectContains<'a, T> for Select<'a, T>
+    where
+        &'a T: Contains,
+    {
+        fn haystack(&self) -> &'a T {
+            self.0
+        }
+    }
+
+    pub trait SelectStrContains<'a, T: ?Sized> {
+        fn haystack(&self) -> AsStr<'a, T>;
+    }
+
+    impl<'a, T: AsRef<str> + ?Sized> SelectStrContains<'a, T> for &Select<'a, T> {
+        fn haystack(&self) -> AsStr<'a, T> {
+            AsStr(self.0)
+        }
+    }
+
+    /// The current unix timestamp in seconds, used by range bounds relative to `now`.
+    pub fn unix_now() -> i64 {
+        match std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH) {
+            Ok(elapsed) => elapsed.as_secs() as i64,
+            Err(before) => -(before.duration().as_secs() as i64),
+        }
+    }
+}
 
 /// Deriving [Validate] allows you to specify schema and field validations on structs.
 /// See the [repository](https://github.com/biblius/validify) for a full list of possible validations.
 pub trait Validate {
     /// Apply the provided validations to self
     fn validate(&self) -> Result<(), ValidationErrors>;
+
+    /// Apply the provided validations to self, including the custom validations declared to run
+    /// in any of the given groups. Groups are passed on to nested validations.
+    fn validate_groups(&self, groups: &[&str]) -> Result<(), ValidationErrors> {
+        let _ = groups;
+        self.validate()
+    }
+
+    /// Runs the validators declared in `warn` and returns their errors as warnings.
+    fn validate_warnings(&self) -> ValidationErrors {
+        ValidationErrors::new()
+    }
+
+    /// Returns both the errors of [Validate::validate] and the warnings of
+    /// [Validate::validate_warnings].
+    fn validate_report(&self) -> ValidationReport {
+        ValidationReport {
+            errors: self.validate().err().unwrap_or_default(),
+            warnings: self.validate_warnings(),
+        }
+    }
 }
 
 /// Modifies the struct based on the provided `modify` parameters. Automatically implemented when deriving Validify.
@@ -36,6 +160,24 @@ pub trait Validate {
 pub trait Modify {
     /// Apply the provided modifiers to self
     fn modify(&mut self);
+
+    /// Apply the provided modifiers to self, including the modifiers declared to run in any of
+    /// the given groups. Groups are passed on to nested modifications.
+    fn modify_groups(&mut self, groups: &[&str]) {
+        let _ = groups;
+        self.modify()
+    }
+
+    /// Returns a modified clone of self, leaving self untouched. Useful for testing modifiers in
+    /// isolation. Modifiers marked with `post_validate` are not applied.
+    fn preview_modifications(&self) -> Self
+    where
+        Self: Clone,
+    {
+        let mut preview = self.clone();
+        preview.modify();
+        preview
+    }
 }
 
 /// Deriving [Validify] allows you to modify structs before they are validated by providing a out of the box validation implementations
@@ -103,6 +245,82 @@ pub trait Modify {
 pub trait Validify: Modify + Validate {
     /// Apply the provided modifiers to self and run validations.
     fn validify(&mut self) -> Result<(), ValidationErrors>;
+
+    /// Apply the provided modifiers and validations to self, including the ones declared to run
+    /// in any of the given groups.
+    fn validify_groups(&mut self, groups: &[&str]) -> Result<(), ValidationErrors> {
+        let _ = groups;
+        self.validify()
+    }
+
+    /// Apply the provided modifiers to self without running any validations. Useful for normalizing
+    /// input that should not be rejected. Modifiers marked with `post_validate` are not applied since
+    /// they depend on validation passing.
+    fn sanitize(&mut self) {
+        <Self as Modify>::modify(self);
+    }
+
+    /// Validifies self without applying the `post_validate` modifiers and `after_validate` functions.
+    /// Used for nested validifies so they are only finished once the outermost struct is valid.
+    #[doc(hidden)]
+    fn __validify_unfinished(&mut self, groups: &[&str]) -> Result<(), ValidationErrors> {
+        self.validify_groups(groups)
+    }
+
+    /// Applies the `post_validate` modifiers and `after_validate` functions of self and its nested
+    /// validifies.
+    #[doc(hidden)]
+    fn __finish_validify(&mut self, groups: &[&str]) {
+        let _ = groups;
+    }
+}
+
+/// Boxed structs are validated, modified and validified through their contents, so nested
+/// fields such as `Option<Vec<Box<T>>>` work the same as their unboxed counterparts.
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        (**self).validate()
+    }
+
+    fn validate_groups(&self, groups: &[&str]) -> Result<(), ValidationErrors> {
+        (**self).validate_groups(groups)
+    }
+
+    fn validate_warnings(&self) -> ValidationErrors {
+        (**self).validate_warnings()
+    }
+}
+
+impl<T: Modify + ?Sized> Modify for Box<T> {
+    fn modify(&mut self) {
+        (**self).modify()
+    }
+
+    fn modify_groups(&mut self, groups: &[&str]) {
+        (**self).modify_groups(groups)
+    }
+}
+
+impl<T: Validify + ?Sized> Validify for Box<T> {
+    fn validify(&mut self) -> Result<(), ValidationErrors> {
+        (**self).validify()
+    }
+
+    fn validify_groups(&mut self, groups: &[&str]) -> Result<(), ValidationErrors> {
+        (**self).validify_groups(groups)
+    }
+
+    fn sanitize(&mut self) {
+        (**self).sanitize()
+    }
+
+    fn __validify_unfinished(&mut self, groups: &[&str]) -> Result<(), ValidationErrors> {
+        (**self).__validify_unfinished(groups)
+    }
+
+    fn __finish_validify(&mut self, groups: &[&str]) {
+        (**self).__finish_validify(groups)
+    }
 }
 
 /// Exposes validify functionality on generated [Payload] structs.
@@ -118,6 +336,67 @@ pub trait ValidifyPayload: Sized {
     fn validify_from(payload: Self::Payload) -> Result<Self, ValidationErrors>;
 }
 
+/// Validates each element of the slice, collecting all the errors. The locations of the errors are prefixed
+/// with the index of the element they originated from, the same way errors from nested collections are.
+///
+/// ```
+/// use validify::{validate_collection, Validate};
+///
+/// #[derive(Debug, Validate)]
+/// struct Item {
+///     #[validate(length(min = 1))]
+///     name: String,
+/// }
+///
+/// let items = vec![Item { name: "".to_string() }, Item { name: "item".to_string() }];
+/// let errs = validate_collection(&items).unwrap_err();
+/// assert_eq!(errs.errors()[0].location(), "/0/name");
+/// ```
+pub fn validate_collection<T: Validate>(items: &[T]) -> Result<(), ValidationErrors> {
+    let mut errors = ValidationErrors::new();
+
+    for (i, item) in items.iter().enumerate() {
+        if let Err(mut errs) = item.validate() {
+            errs.errors_mut()
+                .iter_mut()
+                .for_each(|err| err.set_location(i));
+            errors.merge(errs);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validates each element of the slice, returning the indices of the elements that failed along with
+/// their errors. Unlike [validate_collection], the error locations are not prefixed with the index.
+///
+/// ```
+/// use validify::{validate_many, Validate};
+///
+/// #[derive(Debug, Validate)]
+/// struct Item {
+///     #[validate(length(min = 1))]
+///     name: String,
+/// }
+///
+/// let items = vec![Item { name: "".to_string() }, Item { name: "item".to_string() }];
+/// let failed = validate_many(&items);
+/// assert_eq!(failed.len(), 1);
+/// assert_eq!(failed[0].0, 0);
+/// assert_eq!(failed[0].1.errors()[0].location(), "/name");
+/// ```
+pub fn validate_many<T: Validate>(items: &[T]) -> Vec<(usize, ValidationErrors)> {
+    items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, item)| item.validate().err().map(|errs| (i, errs)))
+        .collect()
+}
+
 /// Creates a new field validation error.
 /// Serves as a shorthand for writing out errors for custom functions
 /// and schema validations.
@@ -139,3 +418,227 @@ macro_rules! field_err {
             .with_message($message.to_string())
     };
 }
+
+/// Defines a preset of validators and modifiers that fields of any struct can apply with
+/// `#[validate(preset = path::to::preset)]`. The preset is a macro, so it is scoped like one and
+/// must be defined before the structs using it, or reached through its path with a visibility
+/// of at most `pub(crate)`.
+///
+/// ```ignore
+/// validify::preset!(pub(crate) username {
+///     modify(trim),
+///     validate(length(min = 1, max = 50), non_control_char)
+/// });
+/// ```
+#[macro_export]
+macro_rules! preset {
+    ($(#[$meta:meta])* $vis:vis $name:ident { $($preset:tt)* }) => {
+        $(#[$meta])*
+        macro_rules! $name {
+            ($input:tt) => {
+                ::validify::__resolve_preset! { $input { $($preset)* } }
+            };
+        }
+
+        #[allow(unused_imports)]
+        $vis use $name;
+    };
+}
+
+/// Used by the code generated for `#[validify(trace_modifications)]`. Emits a `tracing` event
+/// if the modifier changed the field. Without the `tracing` feature it only runs the modifier.
+/// The field must implement `Debug` either way.
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_modification {
+    ($field:expr, $name:literal, $modifier:literal, $modification:block) => {{
+        let before = ::std::format!("{:?}", $field);
+        $modification
+        let after = ::std::format!("{:?}", $field);
+        if before != after {
+            $crate::__private::tracing::debug!(
+                target: "validify",
+                r#struct = ::std::any::type_name::<Self>(),
+                field = $name,
+                modifier = $modifier,
+                before = %before,
+                after = %after,
+                "field modified"
+            );
+        }
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __trace_modification {
+    ($field:expr, $name:literal, $modifier:literal, $modification:block) => {{
+        $crate::__private::assert_debug(&$field);
+        $modification
+    }};
+}
+
+/// Used by the code generated for `#[validate(profile)]`. Emits a `tracing` event with the time
+/// spent validating the field. Requires the `tracing` feature.
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __profile_field {
+    ($name:literal, $validation:block) => {{
+        let start = ::std::time::Instant::now();
+        $validation
+        $crate::__private::tracing::debug!(
+            target: "validify",
+            r#struct = ::std::any::type_name::<Self>(),
+            field = $name,
+            elapsed_us = start.elapsed().as_micros() as u64,
+            "field validated"
+        );
+    }};
+}
+
+#[cfg(not(feature = "tracing"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __profile_field {
+    ($name:literal, $validation:block) => {
+        ::std::compile_error!("`profile` requires the `tracing` feature of validify, or a callback, i.e. `profile = \"metrics::record\"`")
+    };
+}
+
+/// Used by the code generated for `#[modify(par_map(..))]`. Applies the function to each element of
+/// the collection in parallel.
+#[cfg(feature = "rayon")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __par_map {
+    ($collection:expr, $function:path) => {{
+        use $crate::__private::rayon::iter::{IntoParallelRefMutIterator, ParallelIterator};
+        $collection.par_iter_mut().for_each(|el| $function(el));
+    }};
+}
+
+#[cfg(not(feature = "rayon"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __par_map {
+    ($collection:expr, $function:path) => {
+        ::std::compile_error!("`par_map` requires the `rayon` feature of validify")
+    };
+}
+
+/// Used by the code generated for `#[validate(custom(function = .., timeout_ms = ..))]`.
+#[cfg(feature = "timeout")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validate_with_timeout {
+    ($value:expr, $timeout_ms:expr, $function:expr) => {
+        $crate::validate_with_timeout($value, $timeout_ms, $function)
+    };
+}
+
+#[cfg(not(feature = "timeout"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __validate_with_timeout {
+    ($value:expr, $timeout_ms:expr, $function:expr) => {
+        ::std::compile_error!("`timeout_ms` requires the `timeout` feature of validify")
+    };
+}
+
+/// Clamp bounds are not cast to the field's type, so bounds the type cannot hold do not compile.
+///
+/// ```compile_fail
+/// #[derive(validify::Validify)]
+/// struct Clamped {
+///     #[modify(clamp(min = -1, max = 10))]
+///     a: u32,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(validify::Validify)]
+/// struct Clamped {
+///     #[modify(clamp(max = 300))]
+///     a: u8,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// #[derive(validify::Validify)]
+/// struct Clamped {
+///     #[modify(clamp(min = 0.5))]
+///     a: Option<i32>,
+/// }
+/// ```
+///
+/// ```
+/// #[derive(validify::Validify)]
+/// struct Clamped {
+///     #[modify(clamp(min = 0, max = 10))]
+///     a: u8,
+///     #[modify(clamp(min = -1, max = 0.5))]
+///     b: Vec<f32>,
+/// }
+/// ```
+#[cfg(doctest)]
+pub struct ClampBounds;
+
+/// Batch custom modifiers must list at least one field and every listed field must exist.
+///
+/// ```compile_fail
+/// fn normalize(_: &mut String) {}
+///
+/// #[derive(validify::Validify)]
+/// #[modify(custom(function = "normalize"))]
+/// struct Batch {
+///     a: String,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// fn normalize(_: &mut String) {}
+///
+/// #[derive(validify::Validify)]
+/// #[modify(custom(function = "normalize", fields = []))]
+/// struct Batch {
+///     a: String,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// fn normalize(_: &mut String) {}
+///
+/// #[derive(validify::Validify)]
+/// #[modify(custom(function = "normalize", fields = ["a", "b"]))]
+/// struct Batch {
+///     a: String,
+/// }
+/// ```
+///
+/// ```
+/// fn normalize(_: &mut String) {}
+///
+/// #[derive(validify::Validify)]
+/// #[modify(custom(function = "normalize", fields = ["a"]))]
+/// struct Batch {
+///     a: String,
+/// }
+/// ```
+#[cfg(doctest)]
+pub struct BatchModifierFields;
+
+// Workspace builds enable `tracing` through the derive tests, run with `cargo test -p validify`
+#[cfg(all(test, not(feature = "tracing")))]
+mod tests {
+    #[test]
+    fn traced_modifications_run_without_tracing() {
+        let mut field = Some(" Traced ".to_string());
+        crate::__trace_modification!(field, "field", "trim", {
+            field = field.map(|field| field.trim().to_string());
+        });
+        assert_eq!(field.as_deref(), Some("Traced"));
+    }
+}
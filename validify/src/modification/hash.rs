@@ -0,0 +1,49 @@
+use sha2::{Digest, Sha256, Sha512};
+use std::fmt::Write;
+
+/// Returns the lowercase hex encoded SHA-256 digest of the input.
+#[must_use]
+pub fn hash_sha256<T>(input: T) -> String
+where
+    T: AsRef<str>,
+{
+    to_hex(&Sha256::digest(input.as_ref().as_bytes()))
+}
+
+/// Returns the lowercase hex encoded SHA-512 digest of the input.
+#[must_use]
+pub fn hash_sha512<T>(input: T) -> String
+where
+    T: AsRef<str>,
+{
+    to_hex(&Sha512::digest(input.as_ref().as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut acc, byte| {
+            let _ = write!(acc, "{byte:02x}");
+            acc
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_sha256, hash_sha512};
+
+    #[test]
+    fn test_hash_sha256() {
+        assert_eq!(
+            hash_sha256("hello"),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert_eq!(hash_sha256(String::from("hello")).len(), 64);
+    }
+
+    #[test]
+    fn test_hash_sha512() {
+        assert_eq!(hash_sha512("hello").len(), 128);
+        assert!(hash_sha512("hello").starts_with("9b71d224bd62f378"));
+    }
+}
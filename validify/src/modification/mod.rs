@@ -0,0 +1,4 @@
+#[cfg(feature = "hash")]
+pub mod hash;
+pub mod number;
+pub mod scheme;
@@ -0,0 +1,86 @@
+/// Returns the number with its grouping separators and whitespace removed and `.` as its decimal
+/// separator, e.g. `1,234.56` becomes `1234.56` and, with `,` as the `decimal_separator`,
+/// `1.234,56` becomes `1234.56` as well. Inputs that are not numbers once normalized are returned
+/// as they are.
+#[must_use]
+pub fn normalize_number<T>(input: T, decimal_separator: char) -> String
+where
+    T: AsRef<str>,
+{
+    let input = input.as_ref();
+    let normalized = normalize(input, decimal_separator);
+
+    if is_number(&normalized) {
+        normalized
+    } else {
+        input.to_string()
+    }
+}
+
+/// Returns `true` if the input is a number once normalized with [normalize_number]. Used by the
+/// `parse_number` modifier to reject the values it leaves as they are.
+#[must_use]
+pub fn validate_parse_number<T>(input: T, decimal_separator: char) -> bool
+where
+    T: AsRef<str>,
+{
+    is_number(&normalize(input.as_ref(), decimal_separator))
+}
+
+fn normalize(input: &str, decimal_separator: char) -> String {
+    let grouping_separator = if decimal_separator == ',' { '.' } else { ',' };
+    input
+        .chars()
+        .filter(|c| *c != grouping_separator && *c != '\'' && !c.is_whitespace())
+        .map(|c| if c == decimal_separator { '.' } else { c })
+        .collect()
+}
+
+/// Accepts an optional sign followed by digits with at most one decimal point. Unlike parsing
+/// into a float, `inf`, `NaN` and exponents are rejected.
+fn is_number(value: &str) -> bool {
+    let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+    let mut parts = digits.splitn(2, '.');
+    let integer = parts.next().unwrap_or_default();
+    let fraction = parts.next().unwrap_or_default();
+    (!integer.is_empty() || !fraction.is_empty())
+        && integer.chars().all(|c| c.is_ascii_digit())
+        && fraction.chars().all(|c| c.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_number, validate_parse_number};
+
+    #[test]
+    fn test_normalize_number() {
+        let tests = vec![
+            ("1,234.56", '.', "1234.56"),
+            ("1 234 567", '.', "1234567"),
+            ("-12,000", '.', "-12000"),
+            (".5", '.', ".5"),
+            ("1.234,56", ',', "1234.56"),
+            ("1'234,5", ',', "1234.5"),
+            ("1.2.3", '.', "1.2.3"),
+            ("12abc", '.', "12abc"),
+            ("inf", '.', "inf"),
+            ("1e5", '.', "1e5"),
+            ("-", '.', "-"),
+            ("", '.', ""),
+        ];
+
+        for (input, separator, expected) in tests {
+            assert_eq!(normalize_number(input, separator), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_validate_parse_number() {
+        assert!(validate_parse_number("1,234.56", '.'));
+        assert!(validate_parse_number("1234.56", ','));
+        assert!(validate_parse_number("1.234,56", ','));
+        assert!(!validate_parse_number("1.2.3", '.'));
+        assert!(!validate_parse_number("12abc", '.'));
+        assert!(!validate_parse_number("", '.'));
+    }
+}
@@ -0,0 +1,48 @@
+/// Returns the input prefixed with `{scheme}://` if it does not already start with a scheme
+/// followed by `://`, e.g. `example.com` becomes `https://example.com`, while `http://example.com`
+/// is returned as is. Empty inputs are left empty.
+#[must_use]
+pub fn ensure_scheme<T>(input: T, scheme: &str) -> String
+where
+    T: AsRef<str>,
+{
+    let input = input.as_ref();
+    if input.is_empty() || has_scheme(input) {
+        return input.to_string();
+    }
+    format!("{scheme}://{input}")
+}
+
+/// A scheme starts with a letter followed by any number of letters, digits, `+`, `-` or `.`,
+/// as per RFC 3986.
+fn has_scheme(input: &str) -> bool {
+    let Some((scheme, _)) = input.split_once("://") else {
+        return false;
+    };
+    let mut chars = scheme.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ensure_scheme;
+
+    #[test]
+    fn test_ensure_scheme() {
+        let tests = vec![
+            ("example.com", "https://example.com"),
+            ("example.com/a?b=c://d", "https://example.com/a?b=c://d"),
+            ("localhost:8080", "https://localhost:8080"),
+            ("http://example.com", "http://example.com"),
+            ("HTTPS://example.com", "HTTPS://example.com"),
+            ("git+ssh://host/repo", "git+ssh://host/repo"),
+            ("://example.com", "https://://example.com"),
+            ("", ""),
+        ];
+
+        for (input, expected) in tests {
+            assert_eq!(ensure_scheme(input, "https"), expected, "{input}");
+        }
+    }
+}
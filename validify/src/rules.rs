@@ -0,0 +1,47 @@
+use serde::Serialize;
+use serde_json::{to_value, Value};
+use std::collections::HashMap;
+
+/// Describes a validator or modifier applied to a field. Returned by the `rules` function generated
+/// for structs annotated with `#[validate(rules)]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleInfo {
+    /// Whether the rule validates or modifies the field
+    pub kind: RuleKind,
+
+    /// The name of the rule as it is written in the attribute, e.g. `length`
+    pub name: &'static str,
+
+    /// The arguments the rule was specified with. Paths are stored as strings.
+    pub params: HashMap<&'static str, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RuleKind {
+    Validator,
+    Modifier,
+}
+
+impl RuleInfo {
+    pub fn validator(name: &'static str) -> Self {
+        Self {
+            kind: RuleKind::Validator,
+            name,
+            params: HashMap::new(),
+        }
+    }
+
+    pub fn modifier(name: &'static str) -> Self {
+        Self {
+            kind: RuleKind::Modifier,
+            name,
+            params: HashMap::new(),
+        }
+    }
+
+    pub fn with_param<T: Serialize>(mut self, name: &'static str, val: &T) -> Self {
+        self.params.insert(name, to_value(val).unwrap());
+        self
+    }
+}
@@ -1,7 +1,9 @@
+#[cfg(feature = "indexmap")]
 use indexmap::{IndexMap, IndexSet};
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::hash::Hash;
+use std::ops::{Range, RangeInclusive};
 
 /// Trait to implement if one wants to make the `length` validator
 /// work for more types
@@ -15,13 +17,13 @@ impl HasLen for String {
     }
 }
 
-impl<'a> HasLen for &'a String {
+impl HasLen for &String {
     fn length(&self) -> u64 {
         self.chars().count() as u64
     }
 }
 
-impl<'a> HasLen for &'a str {
+impl HasLen for &str {
     fn length(&self) -> u64 {
         self.chars().count() as u64
     }
@@ -39,7 +41,7 @@ impl<T> HasLen for Vec<T> {
     }
 }
 
-impl<'a, T> HasLen for &'a Vec<T> {
+impl<T> HasLen for &Vec<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -63,7 +65,7 @@ impl<T, const N: usize> HasLen for &[T; N] {
     }
 }
 
-impl<'a, K, V, S> HasLen for &'a HashMap<K, V, S> {
+impl<K, V, S> HasLen for &HashMap<K, V, S> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -75,7 +77,7 @@ impl<K, V, S> HasLen for HashMap<K, V, S> {
     }
 }
 
-impl<'a, T, S> HasLen for &'a HashSet<T, S> {
+impl<T, S> HasLen for &HashSet<T, S> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -87,7 +89,7 @@ impl<T, S> HasLen for HashSet<T, S> {
     }
 }
 
-impl<'a, K, V> HasLen for &'a BTreeMap<K, V> {
+impl<K, V> HasLen for &BTreeMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -99,7 +101,7 @@ impl<K, V> HasLen for BTreeMap<K, V> {
     }
 }
 
-impl<'a, T> HasLen for &'a BTreeSet<T> {
+impl<T> HasLen for &BTreeSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
@@ -111,30 +113,41 @@ impl<T> HasLen for BTreeSet<T> {
     }
 }
 
-impl<'a, K, V> HasLen for &'a IndexMap<K, V> {
+#[cfg(feature = "indexmap")]
+impl<K, V> HasLen for &IndexMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
+#[cfg(feature = "indexmap")]
 impl<K, V> HasLen for IndexMap<K, V> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
-impl<'a, T> HasLen for &'a IndexSet<T> {
+#[cfg(feature = "indexmap")]
+impl<T> HasLen for &IndexSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
+#[cfg(feature = "indexmap")]
 impl<T> HasLen for IndexSet<T> {
     fn length(&self) -> u64 {
         self.len() as u64
     }
 }
 
+/// Exposes the discriminants of a fieldless enum to the `one_of(enum_discriminants = "..")` validator.
+/// Usually derived with `#[derive(validify::Discriminants)]`.
+pub trait Discriminants {
+    /// The discriminants of all the enum's variants. Wide enough to hold the discriminants of any `repr`.
+    const DISCRIMINANTS: &'static [i128];
+}
+
 /// Trait to implement if one wants to make the `contains` validator
 /// work for more types
 pub trait Contains {
@@ -148,7 +161,10 @@ impl<T> Contains for Vec<T>
 where
     T: PartialEq,
 {
-    type Needle<'a> = &'a T where Self: 'a;
+    type Needle<'a>
+        = &'a T
+    where
+        Self: 'a;
     fn has_element(&self, needle: Self::Needle<'_>) -> bool {
         self.iter().any(|a| a == needle)
     }
@@ -158,7 +174,10 @@ impl<T> Contains for &Vec<T>
 where
     T: PartialEq,
 {
-    type Needle<'a> = &'a T where Self: 'a;
+    type Needle<'a>
+        = &'a T
+    where
+        Self: 'a;
     fn has_element<'a>(&'a self, needle: Self::Needle<'a>) -> bool {
         self.iter().any(|a| a == needle)
     }
@@ -168,7 +187,10 @@ impl<T> Contains for &[T]
 where
     T: PartialEq,
 {
-    type Needle<'a> = &'a T where Self: 'a;
+    type Needle<'a>
+        = &'a T
+    where
+        Self: 'a;
 
     fn has_element<'a>(&'a self, needle: Self::Needle<'a>) -> bool {
         self.contains(needle)
@@ -179,7 +201,10 @@ impl<T, V> Contains for HashMap<T, V>
 where
     T: PartialEq + Eq + Hash,
 {
-    type Needle<'a> = &'a T where Self: 'a;
+    type Needle<'a>
+        = &'a T
+    where
+        Self: 'a;
     fn has_element<'a>(&'a self, needle: Self::Needle<'a>) -> bool {
         self.contains_key(needle)
     }
@@ -189,7 +214,10 @@ impl<T, V> Contains for &HashMap<T, V>
 where
     T: PartialEq + Eq + Hash,
 {
-    type Needle<'a> = &'a T where Self: 'a;
+    type Needle<'a>
+        = &'a T
+    where
+        Self: 'a;
     fn has_element<'a>(&'a self, needle: Self::Needle<'a>) -> bool {
         self.contains_key(needle)
     }
@@ -203,22 +231,93 @@ impl Contains for String {
 }
 
 impl Contains for &String {
-    type Needle<'a> = &'a str where Self: 'a;
+    type Needle<'a>
+        = &'a str
+    where
+        Self: 'a;
     fn has_element(&self, needle: &str) -> bool {
         self.contains(needle)
     }
 }
 
 impl Contains for &str {
-    type Needle<'a> = &'a str where Self: 'a;
+    type Needle<'a>
+        = &'a str
+    where
+        Self: 'a;
     fn has_element(&self, needle: &str) -> bool {
         self.contains(needle)
     }
 }
 
 impl Contains for Cow<'_, str> {
-    type Needle<'a> = &'a str where Self: 'a;
+    type Needle<'a>
+        = &'a str
+    where
+        Self: 'a;
     fn has_element(&self, needle: &str) -> bool {
         self.contains(needle)
     }
 }
+
+/// Adapts any `AsRef<str>` type to [HasLen] and [Contains], counting and searching its chars.
+/// The derive falls back to it for string types that implement neither trait, such as
+/// `SmolStr` or `CompactString`.
+pub struct AsStr<'a, T: ?Sized>(pub &'a T);
+
+impl<T: AsRef<str> + ?Sized> HasLen for AsStr<'_, T> {
+    fn length(&self) -> u64 {
+        self.0.as_ref().chars().count() as u64
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Contains for AsStr<'_, T> {
+    type Needle<'a>
+        = &'a str
+    where
+        Self: 'a;
+    fn has_element(&self, needle: &str) -> bool {
+        self.0.as_ref().contains(needle)
+    }
+}
+
+/// Trait to implement if one wants to make the `valid_range` and `range_len` validators
+/// work for more types
+pub trait Span {
+    /// Returns `true` if the start of the span is not after its end.
+    fn is_ordered(&self) -> bool;
+
+    /// Returns the number of values in the span, 0 if it is not ordered.
+    fn span_len(&self) -> u64;
+}
+
+macro_rules! impl_span {
+    ($($ty:ty),*) => {
+        $(
+            impl Span for Range<$ty> {
+                fn is_ordered(&self) -> bool {
+                    self.start <= self.end
+                }
+
+                fn span_len(&self) -> u64 {
+                    (self.end as i128 - self.start as i128).clamp(0, u64::MAX as i128) as u64
+                }
+            }
+
+            impl Span for RangeInclusive<$ty> {
+                fn is_ordered(&self) -> bool {
+                    self.start() <= self.end()
+                }
+
+                fn span_len(&self) -> u64 {
+                    if !self.is_ordered() {
+                        return 0;
+                    }
+                    (*self.end() as i128 - *self.start() as i128 + 1).min(u64::MAX as i128) as u64
+                }
+            }
+        )*
+    };
+}
+
+impl_span!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
@@ -11,6 +11,27 @@ pub fn validate_contains<T: Contains>(haystack: T, needle: T::Needle<'_>, not: b
     }
 }
 
+/// Validates whether the value contains the word as a whole. Both are split into words on every
+/// character that is not alphanumeric, i.e. whitespace and punctuation, and the words of `word` must
+/// appear consecutively in the value. The comparison is case sensitive.
+#[must_use]
+pub fn validate_contains_word<T: AsRef<str>>(haystack: T, word: &str) -> bool {
+    let needle = words(word);
+    if needle.is_empty() {
+        return false;
+    }
+
+    words(haystack.as_ref())
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+fn words(s: &str) -> Vec<&str> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
@@ -97,4 +118,16 @@ mod tests {
         let test: Cow<'static, str> = String::from("hey").into();
         assert!(validate_contains(test, "o", true));
     }
+
+    #[test]
+    fn test_validate_contains_word() {
+        assert!(validate_contains_word("the cat sat", "cat"));
+        assert!(validate_contains_word("cat", "cat"));
+        assert!(validate_contains_word("a cat, a dog.", "dog"));
+        assert!(validate_contains_word("the black-cat sat", "black cat"));
+        assert!(!validate_contains_word("category", "cat"));
+        assert!(!validate_contains_word("the Cat sat", "cat"));
+        assert!(!validate_contains_word("the cat sat", "the sat"));
+        assert!(!validate_contains_word("the cat sat", " "));
+    }
 }
@@ -54,6 +54,25 @@ where
     true
 }
 
+/// Validates whether the domain of the given email has a top level domain, i.e. contains a dot
+/// followed by a non-empty segment. Address literals such as `user@[127.0.0.1]` have none.
+/// Meant to be used in addition to [validate_email].
+#[must_use]
+pub fn validate_email_tld<T>(val: T) -> bool
+where
+    T: AsRef<str>,
+{
+    let Some((_, domain)) = val.as_ref().rsplit_once('@') else {
+        return false;
+    };
+
+    if domain.starts_with('[') {
+        return false;
+    }
+
+    matches!(domain.rsplit_once('.'), Some((name, tld)) if !name.is_empty() && !tld.is_empty())
+}
+
 /// Checks if the domain is a valid domain and if not, check whether it's an IP
 #[must_use]
 fn validate_domain_part(domain_part: &str) -> bool {
@@ -74,7 +93,7 @@ fn validate_domain_part(domain_part: &str) -> bool {
 #[cfg(test)]
 mod tests {
 
-    use super::validate_email;
+    use super::{validate_email, validate_email_tld};
 
     #[test]
     fn test_validate_email() {
@@ -163,4 +182,15 @@ mod tests {
         let test = "a@aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa.com";
         assert!(!validate_email(test));
     }
+
+    #[test]
+    fn test_validate_email_tld() {
+        assert!(validate_email_tld("user@example.com"));
+        assert!(validate_email_tld("user@mail.example.co.uk"));
+        assert!(!validate_email_tld("user@localhost"));
+        assert!(!validate_email_tld("user@example."));
+        assert!(!validate_email_tld("user@.com"));
+        assert!(!validate_email_tld("user@[127.0.0.1]"));
+        assert!(!validate_email_tld("user"));
+    }
 }
@@ -7,7 +7,7 @@ pub fn validate_ip_v4<T>(val: T) -> bool
 where
     T: AsRef<str>,
 {
-    IpAddr::from_str(val.as_ref()).map_or(false, |i| i.is_ipv4())
+    IpAddr::from_str(val.as_ref()).is_ok_and(|i| i.is_ipv4())
 }
 
 /// Validates whether the given string is an IP V6
@@ -16,7 +16,7 @@ pub fn validate_ip_v6<T>(val: T) -> bool
 where
     T: AsRef<str>,
 {
-    IpAddr::from_str(val.as_ref()).map_or(false, |i| i.is_ipv6())
+    IpAddr::from_str(val.as_ref()).is_ok_and(|i| i.is_ipv6())
 }
 
 /// Validates whether the given string is an IP
@@ -32,11 +32,30 @@ pub fn validate_length<T: HasLen>(
     true
 }
 
+/// Like [validate_length], but only counts the chars of the string that are not whitespace.
+#[must_use]
+pub fn validate_non_whitespace_length(
+    value: &str,
+    min: Option<u64>,
+    max: Option<u64>,
+    equal: Option<u64>,
+) -> bool {
+    validate_length(NonWhitespace(value), min, max, equal)
+}
+
+struct NonWhitespace<'a>(&'a str);
+
+impl HasLen for NonWhitespace<'_> {
+    fn length(&self) -> u64 {
+        self.0.chars().filter(|c| !c.is_whitespace()).count() as u64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
 
-    use super::validate_length;
+    use super::{validate_length, validate_non_whitespace_length};
 
     #[test]
     fn test_validate_length_equal_overrides_min_max() {
@@ -76,4 +95,22 @@ mod tests {
     fn test_validate_length_unicode_chars() {
         assert!(validate_length("日本", None, None, Some(2)));
     }
+
+    #[test]
+    fn test_validate_non_whitespace_length() {
+        assert!(validate_non_whitespace_length(
+            "  a b\tc \n",
+            None,
+            Some(3),
+            None
+        ));
+        assert!(!validate_non_whitespace_length(
+            "  a b\tc \n",
+            None,
+            Some(2),
+            None
+        ));
+        assert!(validate_non_whitespace_length("ž ć", None, None, Some(2)));
+        assert!(!validate_non_whitespace_length("    ", Some(1), None, None));
+    }
 }
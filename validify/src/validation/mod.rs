@@ -6,8 +6,13 @@ pub mod ip;
 pub mod length;
 pub mod must_match;
 pub mod non_control_char;
+pub mod one_of;
 pub mod phone;
 pub mod range;
 pub mod required;
+pub mod span;
+#[cfg(feature = "chrono")]
 pub mod time;
+#[cfg(feature = "timeout")]
+pub mod timeout;
 pub mod urls;
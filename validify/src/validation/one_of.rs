@@ -0,0 +1,34 @@
+/// Validates whether the string is one of the allowed values. If `case_insensitive` is true,
+/// the value is lowercased before comparing, so the allowed values must already be lowercase.
+/// The derive lowercases them when expanding the macro.
+#[must_use]
+pub fn validate_one_of<T>(value: T, allowed: &[&str], case_insensitive: bool) -> bool
+where
+    T: AsRef<str>,
+{
+    let value = value.as_ref();
+
+    if case_insensitive {
+        allowed.contains(&value.to_lowercase().as_str())
+    } else {
+        allowed.contains(&value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_one_of;
+
+    #[test]
+    fn test_one_of() {
+        let allowed = ["draft", "published"];
+
+        assert!(validate_one_of("draft", &allowed, false));
+        assert!(!validate_one_of("Draft", &allowed, false));
+        assert!(!validate_one_of("archived", &allowed, false));
+
+        assert!(validate_one_of("Draft", &allowed, true));
+        assert!(validate_one_of(String::from("PUBLISHED"), &allowed, true));
+        assert!(!validate_one_of("Archived", &allowed, true));
+    }
+}
@@ -21,9 +21,18 @@ where
     true
 }
 
+/// Validates that the given `value` is a multiple of `step` away from `base`. Floats are compared
+/// with a tolerance relative to the number of steps, so values such as `0.3` with a step of `0.1`
+/// are accepted.
+#[must_use]
+pub fn validate_step(value: f64, base: f64, step: f64) -> bool {
+    let steps = (value - base) / step;
+    (steps - steps.round()).abs() <= steps.abs().max(1.) * 1e-9
+}
+
 #[cfg(test)]
 mod tests {
-    use super::validate_range;
+    use super::{validate_range, validate_step};
 
     #[test]
     fn test_validate_range_generic_ok() {
@@ -54,4 +63,15 @@ mod tests {
         assert!(validate_range(5, None, Some(10)));
         assert!(!validate_range(15, None, Some(10)));
     }
+
+    #[test]
+    fn test_validate_step() {
+        assert!(validate_step(10., 0., 5.));
+        assert!(validate_step(-15., 0., 5.));
+        assert!(!validate_step(7., 0., 5.));
+        assert!(validate_step(8., 3., 5.));
+        assert!(!validate_step(10., 3., 5.));
+        assert!(validate_step(0.3, 0., 0.1));
+        assert!(!validate_step(0.35, 0., 0.1));
+    }
 }
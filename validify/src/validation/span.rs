@@ -0,0 +1,39 @@
+use crate::traits::Span;
+
+/// Validates that the start of the span is not after its end.
+#[must_use]
+pub fn validate_valid_range<T: Span>(value: &T) -> bool {
+    value.is_ordered()
+}
+
+/// Validates the number of values in the span. Spans whose start is after their end are
+/// considered empty.
+#[must_use]
+pub fn validate_range_len<T: Span>(value: &T, min: Option<u64>, max: Option<u64>) -> bool {
+    let len = value.span_len();
+    min.is_none_or(|min| len >= min) && max.is_none_or(|max| len <= max)
+}
+
+#[cfg(test)]
+#[allow(clippy::reversed_empty_ranges)]
+mod tests {
+    use super::{validate_range_len, validate_valid_range};
+
+    #[test]
+    fn test_validate_valid_range() {
+        assert!(validate_valid_range(&(1u32..5)));
+        assert!(validate_valid_range(&(5u32..5)));
+        assert!(!validate_valid_range(&(6u32..5)));
+        assert!(validate_valid_range(&(-3i64..=-3)));
+        assert!(!validate_valid_range(&(2i8..=1)));
+    }
+
+    #[test]
+    fn test_validate_range_len() {
+        assert!(validate_range_len(&(0u32..1000), None, Some(1000)));
+        assert!(!validate_range_len(&(0u32..1001), None, Some(1000)));
+        assert!(!validate_range_len(&(0u32..=1000), None, Some(1000)));
+        assert!(validate_range_len(&(i64::MIN..i64::MAX), Some(1), None));
+        assert!(!validate_range_len(&(5u8..2), Some(1), None));
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
 pub fn before_now(actual: &NaiveDateTime, eq: bool) -> bool {
     let now = chrono::Utc::now().naive_utc();
@@ -86,6 +86,38 @@ pub fn in_period_date(actual: &NaiveDate, start: &NaiveDate, duration: chrono::D
     }
 }
 
+/// Checks whether the datetime is in the future. The datetime is allowed to be up to `grace` seconds
+/// in the past to account for clock skew. Returns the timestamp the datetime was compared against on failure.
+/// A grace period reaching past the representable range accepts every datetime.
+pub fn future<Tz: TimeZone>(actual: &DateTime<Tz>, grace: i64) -> Result<(), DateTime<Utc>> {
+    let Some(target) =
+        chrono::Duration::try_seconds(grace).and_then(|grace| Utc::now().checked_sub_signed(grace))
+    else {
+        return Ok(());
+    };
+    if actual.with_timezone(&Utc) > target {
+        Ok(())
+    } else {
+        Err(target)
+    }
+}
+
+/// Checks whether the datetime is in the past. The datetime is allowed to be up to `grace` seconds
+/// in the future to account for clock skew. Returns the timestamp the datetime was compared against on failure.
+/// A grace period reaching past the representable range accepts every datetime.
+pub fn past<Tz: TimeZone>(actual: &DateTime<Tz>, grace: i64) -> Result<(), DateTime<Utc>> {
+    let Some(target) =
+        chrono::Duration::try_seconds(grace).and_then(|grace| Utc::now().checked_add_signed(grace))
+    else {
+        return Ok(());
+    };
+    if actual.with_timezone(&Utc) < target {
+        Ok(())
+    } else {
+        Err(target)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::time::*;
@@ -411,4 +443,22 @@ mod tests {
             NaiveDateTime::parse_from_str("2000-01-01T10:00:00", "%Y-%m-%dT%H:%M:%S").unwrap();
         assert!(!in_period(&actual, &target, chrono::Duration::minutes(-10)));
     }
+
+    #[test]
+    fn _future_and_past() {
+        let now = Utc::now();
+
+        assert!(future(&(now + chrono::Duration::minutes(1)), 0).is_ok());
+        assert!(future(&(now - chrono::Duration::minutes(1)), 0).is_err());
+        assert!(future(&(now - chrono::Duration::minutes(1)), 300).is_ok());
+
+        assert!(past(&(now - chrono::Duration::minutes(1)), 0).is_ok());
+        assert!(past(&(now + chrono::Duration::minutes(1)), 0).is_err());
+        assert!(past(&(now + chrono::Duration::minutes(1)), 300).is_ok());
+
+        let offset = chrono::FixedOffset::east_opt(3600).unwrap();
+        let local = (now - chrono::Duration::minutes(1)).with_timezone(&offset);
+        let target = future(&local, 0).unwrap_err();
+        assert!(target >= now);
+    }
 }
@@ -0,0 +1,57 @@
+use crate::ValidationError;
+use std::{sync::mpsc, thread, time::Duration};
+
+/// Runs the validation function on a separate thread, returning a `custom_timeout` error if it does
+/// not finish within `timeout_ms` milliseconds.
+///
+/// Since the thread cannot be cancelled, it is detached on timeout and left to finish in the background.
+/// Every timed out call leaks its thread, along with the value moved to it, until the function returns.
+/// A function that never returns keeps its thread alive for the rest of the process, so repeatedly timing
+/// out on such a function grows the number of threads without bound. A scoped thread would avoid the leak,
+/// but the scope joins the thread before returning, blocking past the timeout.
+///
+/// Because the thread may outlive the call, the value is moved to the thread and both the value and function
+/// must be `Send` and `'static`. When used through `#[validate(custom(function = .., timeout_ms = ..))]`, the
+/// field is cloned.
+pub fn validate_with_timeout<T, F>(value: T, timeout_ms: u64, f: F) -> Result<(), ValidationError>
+where
+    T: Send + 'static,
+    F: FnOnce(T) -> Result<(), ValidationError> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+
+    thread::spawn(move || {
+        // The receiver is gone if we timed out, nothing to do
+        let _ = tx.send(f(value));
+    });
+
+    match rx.recv_timeout(Duration::from_millis(timeout_ms)) {
+        Ok(result) => result,
+        Err(_) => {
+            Err(ValidationError::new_field("custom_timeout").with_param("timeout_ms", &timeout_ms))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::validate_with_timeout;
+    use crate::ValidationError;
+    use std::time::Duration;
+
+    #[test]
+    fn test_validate_with_timeout() {
+        assert!(validate_with_timeout(1, 1000, |_| Ok(())).is_ok());
+
+        let err = validate_with_timeout(1, 1000, |_| Err(ValidationError::new_field("nope")));
+        assert_eq!(err.unwrap_err().code(), "nope");
+
+        let err = validate_with_timeout(1, 10, |_| {
+            std::thread::sleep(Duration::from_millis(500));
+            Ok(())
+        });
+        let err = err.unwrap_err();
+        assert_eq!(err.code(), "custom_timeout");
+        assert_eq!(err.params()["timeout_ms"], 10);
+    }
+}
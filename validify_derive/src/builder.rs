@@ -0,0 +1,113 @@
+use crate::fields::{try_extract_option, FieldInfo};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generates a builder with a setter for each field. Building fails with a `required` error for
+/// every non optional field that was not set and otherwise validifies the struct.
+pub fn quote_builder(input: &syn::DeriveInput, fields: &[FieldInfo]) -> TokenStream {
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let builder_ident = format_ident!("{ident}Builder");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let generics = &input.generics;
+
+    let mut builder_fields = vec![];
+    let mut defaults = vec![];
+    let mut setters = vec![];
+    let mut required_checks = vec![];
+    let mut assignments = vec![];
+
+    for field in fields {
+        let field_ident = field.field.ident.as_ref().unwrap();
+        let cfg_attrs = field.cfg_attrs();
+        let field_name = field.name();
+
+        // Zero sized fields are always defaulted and have no setter
+        if field.is_zero_sized() {
+            let ty = &field.field.ty;
+            builder_fields.push(quote!(#(#cfg_attrs)* #field_ident: #ty,));
+            defaults.push(quote!(#(#cfg_attrs)* #field_ident: ::std::default::Default::default(),));
+            assignments.push(quote!(#(#cfg_attrs)* #field_ident: self.#field_ident,));
+            continue;
+        }
+
+        // Optional fields are stored as they are and set with their inner type
+        let (builder_ty, setter_ty) = match try_extract_option(&field.field.ty) {
+            Some(inner) => (field.field.ty.clone(), inner.clone()),
+            None => {
+                let ty = &field.field.ty;
+                (syn::parse_quote!(::std::option::Option<#ty>), ty.clone())
+            }
+        };
+
+        builder_fields.push(quote!(#(#cfg_attrs)* #field_ident: #builder_ty,));
+        defaults.push(quote!(#(#cfg_attrs)* #field_ident: ::std::option::Option::None,));
+        setters.push(quote!(
+            #(#cfg_attrs)*
+            pub fn #field_ident(mut self, #field_ident: impl ::std::convert::Into<#setter_ty>) -> Self {
+                self.#field_ident = ::std::option::Option::Some(#field_ident.into());
+                self
+            }
+        ));
+
+        if field.is_option() {
+            assignments.push(quote!(#(#cfg_attrs)* #field_ident: self.#field_ident,));
+        } else {
+            required_checks.push(quote!(
+                #(#cfg_attrs)*
+                if self.#field_ident.is_none() {
+                    let mut err = ::validify::ValidationError::new_field_named(#field_name, "required");
+                    err.set_location(#field_name);
+                    errors.add(err);
+                }
+            ));
+            assignments.push(quote!(#(#cfg_attrs)* #field_ident: self.#field_ident.unwrap(),));
+        }
+    }
+
+    quote!(
+        /// Builder validifying the struct once built.
+        #vis struct #builder_ident #generics #where_clause {
+            #(#builder_fields)*
+        }
+
+        impl #impl_generics ::std::default::Default for #builder_ident #ty_generics #where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#defaults)*
+                }
+            }
+        }
+
+        impl #impl_generics #builder_ident #ty_generics #where_clause {
+            #(#setters)*
+
+            /// Runs the modifiers and validations on the built struct. Fails with a `required`
+            /// error for each non optional field that was not set.
+            pub fn build(self) -> ::std::result::Result<#ident #ty_generics, ::validify::ValidationErrors> {
+                let mut errors = ::validify::ValidationErrors::new();
+
+                #(#required_checks)*
+
+                if !errors.is_empty() {
+                    return ::std::result::Result::Err(errors);
+                }
+
+                let mut built = #ident {
+                    #(#assignments)*
+                };
+
+                ::validify::Validify::validify(&mut built)?;
+
+                ::std::result::Result::Ok(built)
+            }
+        }
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns a builder validifying the struct once built.
+            pub fn builder() -> #builder_ident #ty_generics {
+                ::std::default::Default::default()
+            }
+        }
+    )
+}
@@ -0,0 +1,36 @@
+use proc_macro_error::abort;
+use quote::quote;
+use syn::spanned::Spanned;
+
+pub fn impl_discriminants(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let ident = &input.ident;
+
+    let syn::Data::Enum(ref data) = input.data else {
+        abort!(
+            input.span(),
+            "Discriminants can only be derived on fieldless enums"
+        )
+    };
+
+    let variants = data
+        .variants
+        .iter()
+        .map(|variant| {
+            if !matches!(variant.fields, syn::Fields::Unit) {
+                abort!(
+                    variant.span(),
+                    "Discriminants can only be derived on fieldless enums"
+                )
+            }
+            &variant.ident
+        })
+        .collect::<Vec<_>>();
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::validify::Discriminants for #ident #ty_generics #where_clause {
+            const DISCRIMINANTS: &'static [i128] = &[#(Self::#variants as i128),*];
+        }
+    )
+}
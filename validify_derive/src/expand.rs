@@ -0,0 +1,86 @@
+//! Snapshot tests of the generated code. Each file in `tests/expand` is expanded with the derives
+//! listed on its structs and compared to the committed `.expanded.rs` file next to it. Run the
+//! tests with `VALIDIFY_BLESS=1` to overwrite the snapshots after an intended change.
+
+use std::{fs, path::Path};
+
+fn expand(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let mut derives = vec![];
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+    {
+        attr.parse_nested_meta(|meta| {
+            derives.extend(meta.path.get_ident().map(ToString::to_string));
+            Ok(())
+        })
+        .unwrap();
+    }
+
+    let mut tokens = proc_macro2::TokenStream::new();
+    for derive in derives {
+        match derive.as_str() {
+            "Validate" => tokens.extend(crate::validate::r#impl::impl_validate(input)),
+            "Validify" => tokens.extend(crate::validify::r#impl::impl_validify(input)),
+            "Payload" => tokens.extend(crate::payload::r#impl::impl_payload(input)),
+            _ => {}
+        }
+    }
+    tokens
+}
+
+fn expand_file(path: &Path) -> String {
+    let source = fs::read_to_string(path).unwrap();
+    let file = syn::parse_file(&source).unwrap();
+
+    let tokens = file
+        .items
+        .into_iter()
+        .filter_map(|item| match item {
+            syn::Item::Struct(item) => Some(expand(&syn::DeriveInput::from(item))),
+            _ => None,
+        })
+        .collect::<proc_macro2::TokenStream>();
+
+    prettyplease::unparse(&syn::parse2(tokens).unwrap())
+}
+
+#[test]
+fn expansions_match_snapshots() {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/expand");
+    let bless = std::env::var_os("VALIDIFY_BLESS").is_some();
+
+    let mut inputs: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "rs")
+                && !path.to_string_lossy().ends_with(".expanded.rs")
+        })
+        .collect();
+    inputs.sort();
+    assert!(!inputs.is_empty(), "no inputs found in {}", dir.display());
+
+    for input in inputs {
+        let expanded = expand_file(&input);
+        let snapshot = input.with_extension("expanded.rs");
+
+        if bless {
+            fs::write(&snapshot, &expanded).unwrap();
+            continue;
+        }
+
+        let expected = fs::read_to_string(&snapshot).unwrap_or_else(|_| {
+            panic!(
+                "missing snapshot {}, run the tests with VALIDIFY_BLESS=1 to create it",
+                snapshot.display()
+            )
+        });
+        assert!(
+            expected == expanded,
+            "expansion of {} does not match its snapshot, run the tests with VALIDIFY_BLESS=1 if the change is intended\n\n{expanded}",
+            input.display()
+        );
+    }
+}
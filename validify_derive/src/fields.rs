@@ -4,7 +4,7 @@ use crate::{
     validify::{modifier::Modifier, r#impl::collect_modifiers},
 };
 use proc_macro_error::abort;
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 
 /// Holds the combined validations and modifiers for one field
@@ -26,6 +26,16 @@ pub struct FieldInfo {
     pub modifiers: Vec<Modifier>,
 
     pub rename_rule: Option<RenameRule>,
+
+    /// The sibling bool field indicating whether this field's value was defaulted, added to the params of its errors.
+    pub was_defaulted: Option<syn::Ident>,
+
+    /// The values of the struct's tag field for which the validations run. Empty if they always run.
+    pub variants: Vec<String>,
+
+    /// Whether the validations are quoted as warnings, in which case nested structs are checked
+    /// for their warnings instead of their errors.
+    pub warnings: bool,
 }
 
 impl FieldInfo {
@@ -36,6 +46,7 @@ impl FieldInfo {
         validations: Vec<Validator>,
         modifiers: Vec<Modifier>,
         rename_rule: Option<RenameRule>,
+        was_defaulted: Option<syn::Ident>,
     ) -> Self {
         FieldInfo {
             field,
@@ -44,6 +55,9 @@ impl FieldInfo {
             validations,
             modifiers,
             rename_rule,
+            was_defaulted,
+            variants: vec![],
+            warnings: false,
         }
     }
 
@@ -58,29 +72,51 @@ impl FieldInfo {
 
         let rename_rule = crate::serde::find_rename_all(&input.attrs);
 
+        let shared_rules = crate::preset::collect_shared_rules(&input.attrs, fields);
+
         fields
             .into_iter()
             .map(|field| {
+                let field = &crate::preset::apply_shared_rules(field.clone(), &shared_rules);
+
                 let field_ident = field
                     .ident
                     .as_ref()
                     .expect("Found unnamed field")
                     .to_string();
 
-                let validations = collect_validations(field);
+                let (validations, was_defaulted, variants) = collect_validations(field);
                 let modifiers = collect_modifiers(field);
 
                 // The original name refers to the field name set with serde rename.
                 let original_name = crate::serde::find_rename(field);
 
-                Self::new(
-                    field.clone(),
-                    field_ident,
-                    original_name,
-                    validations,
-                    modifiers,
-                    rename_rule,
-                )
+                let info = Self {
+                    variants,
+                    ..Self::new(
+                        field.clone(),
+                        field_ident,
+                        original_name,
+                        validations,
+                        modifiers,
+                        rename_rule,
+                        was_defaulted,
+                    )
+                };
+
+                if info.is_zero_sized()
+                    && !(info.validations.is_empty() && info.modifiers.is_empty())
+                {
+                    abort!(
+                        field.span(),
+                        format!(
+                            "Field `{}` is zero sized and has nothing to validate or modify",
+                            info.name
+                        )
+                    )
+                }
+
+                info
             })
             .collect::<Vec<_>>()
     }
@@ -114,25 +150,69 @@ impl FieldInfo {
         }
 
         nested_validations.extend(quoted_validations);
-        nested_validations
+
+        let Some(ref flag) = self.was_defaulted else {
+            return nested_validations;
+        };
+
+        // Annotate any errors originating from this field
+        vec![quote!(
+            let __errors_before = errors.errors().len();
+            #(#nested_validations)*
+            let __was_defaulted: bool = self.#flag;
+            for err in errors.errors_mut()[__errors_before..].iter_mut() {
+                err.add_param("was_defaulted", &__was_defaulted);
+            }
+        )]
     }
 
-    /// Returns the modification tokens as the first element and any nested validifes as the second.
+    /// Returns the modification tokens as the first element, any nested validifes as the second and
+    /// the modifications that must run only after successful validation as the third.
     pub fn quote_validifes(
         &self,
-    ) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
+        trace: bool,
+    ) -> (
+        Vec<proc_macro2::TokenStream>,
+        Vec<proc_macro2::TokenStream>,
+        Vec<proc_macro2::TokenStream>,
+    ) {
         let mut nested_validifies = vec![];
         let mut quoted_modifications = vec![];
+        let mut post_validate_modifications = vec![];
 
         for modifier in self.modifiers.iter() {
-            let (tokens, nested) = modifier.to_validify_tokens(self);
-            quoted_modifications.push(tokens);
+            let (mut tokens, nested) = modifier.to_validify_tokens(self);
+            if trace && !matches!(modifier, Modifier::Nested | Modifier::Convert { .. }) {
+                let ident = &self.field.ident;
+                let field_name = &self.name;
+                let modifier_name = modifier.name();
+                tokens = quote!(
+                    ::validify::__trace_modification!(self.#ident, #field_name, #modifier_name, { #tokens });
+                );
+            }
+            if modifier.is_post_validate() {
+                post_validate_modifications.push(tokens);
+            } else {
+                quoted_modifications.push(tokens);
+            }
             if let Some(nested) = nested {
                 nested_validifies.push(nested);
             }
+            if let Some(finish) = modifier.quote_nested_finish(self) {
+                post_validate_modifications.push(finish);
+            }
+        }
+
+        // Control flow modifiers break out of the block to skip the remaining modifiers
+        if self.modifiers.iter().any(Modifier::is_control_flow) {
+            quoted_modifications = vec![quote!('modifiers: { #(#quoted_modifications)* })];
         }
 
-        (quoted_modifications, nested_validifies)
+        (
+            quoted_modifications,
+            nested_validifies,
+            post_validate_modifications,
+        )
     }
 
     /// Quotes the field as necessary for passing the resulting tokens into a validation
@@ -149,7 +229,7 @@ impl FieldInfo {
         if self.is_option() {
             return quote!(#ident);
         }
-        match self.field.ty {
+        match strip_type(&self.field.ty) {
             syn::Type::Reference(_) => {
                 quote!(self.#ident)
             }
@@ -215,6 +295,75 @@ impl FieldInfo {
         tokens
     }
 
+    /// Returns the element indices of the field's tuple type. Aborts if the field is not a tuple
+    /// or its elements are not all of the same type.
+    pub fn homogeneous_tuple_len(&self) -> std::ops::Range<usize> {
+        let syn::Type::Tuple(ref tuple) = self.field.ty else {
+            abort!(self.field.ty.span(), "`each` can only be used on tuples")
+        };
+
+        let mut types = tuple
+            .elems
+            .iter()
+            .map(|ty| ty.to_token_stream().to_string());
+        if let Some(first) = types.next() {
+            if types.any(|ty| ty != first) {
+                abort!(
+                    self.field.ty.span(),
+                    "`each` can only be used on tuples whose elements are of the same type"
+                )
+            }
+        }
+
+        0..tuple.elems.len()
+    }
+
+    /// Returns the `cfg` attributes of the field. Any code generated for the field must be gated
+    /// by them since the field does not exist when they are not satisfied.
+    pub fn cfg_attrs(&self) -> Vec<&syn::Attribute> {
+        self.field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("cfg"))
+            .collect()
+    }
+
+    /// Wrap the quoted statements in a block gated by the field's `cfg` attributes if it has any.
+    pub fn wrap_tokens_if_cfg(
+        &self,
+        tokens: Vec<proc_macro2::TokenStream>,
+    ) -> Vec<proc_macro2::TokenStream> {
+        let cfg_attrs = self.cfg_attrs();
+
+        if cfg_attrs.is_empty() || tokens.is_empty() {
+            return tokens;
+        }
+
+        vec![quote!(
+            #(#cfg_attrs)*
+            {
+                #(#tokens)*
+            }
+        )]
+    }
+
+    /// Same as [Self::wrap_tokens_if_option], but runs `none_tokens` when the field is `None`.
+    pub fn wrap_tokens_if_option_or_else(
+        &self,
+        tokens: proc_macro2::TokenStream,
+        none_tokens: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let field_ident = &self.field.ident;
+        let this = self.option_self_tokens_validation();
+        quote!(
+            if let #this = self.#field_ident {
+                #tokens
+            } else {
+                #none_tokens
+            }
+        )
+    }
+
     /// Wrap the quoted output of a validation with a for loop if
     /// the field type is a collection.
     pub fn wrap_validator_if_collection(
@@ -233,7 +382,7 @@ impl FieldInfo {
         if self.is_list() {
             quote!(
                 for (i, item) in #prefix #param.iter().enumerate() {
-                    if let Err(mut errs) = item.validate() {
+                    if let Err(mut errs) = item.validate_groups(__groups) {
                         errs.errors_mut().iter_mut().for_each(|err| err.set_location_idx(i, #field_name));
                         errors.merge(errs);
                     }
@@ -242,7 +391,7 @@ impl FieldInfo {
         } else if self.is_map() {
             quote!(
                 for (key, item) in #prefix #param.iter() {
-                    if let Err(mut errs) = item.validate() {
+                    if let Err(mut errs) = item.validate_groups(__groups) {
                         errs.errors_mut().iter_mut().for_each(|err| err.set_location_idx(key, #field_name));
                         errors.merge(errs);
                     }
@@ -253,6 +402,40 @@ impl FieldInfo {
         }
     }
 
+    /// Quotes the warnings of the nested struct, or of each of the nested structs if the field is a
+    /// collection, with their locations prefixed by the field's.
+    pub fn quote_nested_warnings(&self) -> proc_macro2::TokenStream {
+        let param = self.quote_validator_field();
+        let field_name = self.name();
+
+        // Same as in `wrap_validator_if_collection`
+        let prefix = (!self.is_option()).then(|| quote! { self. });
+
+        if self.is_list() {
+            quote!(
+                for (i, item) in #prefix #param.iter().enumerate() {
+                    let mut warnings = item.validate_warnings();
+                    warnings.errors_mut().iter_mut().for_each(|err| err.set_location_idx(i, #field_name));
+                    errors.merge(warnings);
+                }
+            )
+        } else if self.is_map() {
+            quote!(
+                for (key, item) in #prefix #param.iter() {
+                    let mut warnings = item.validate_warnings();
+                    warnings.errors_mut().iter_mut().for_each(|err| err.set_location_idx(key, #field_name));
+                    errors.merge(warnings);
+                }
+            )
+        } else {
+            quote!(
+                let mut warnings = #param.validate_warnings();
+                warnings.errors_mut().iter_mut().for_each(|err| err.set_location(#field_name));
+                errors.merge(warnings);
+            )
+        }
+    }
+
     pub fn wrap_modifier_if_option(
         &self,
         tokens: proc_macro2::TokenStream,
@@ -290,6 +473,15 @@ impl FieldInfo {
             Modifier::Capitalize => {
                 quote!(::std::format!("{}{}", &el[0..1].to_uppercase(), &el[1..]))
             }
+            Modifier::Hash { algo, .. } => {
+                let hash_fn = algo.hash_fn();
+                quote!(#hash_fn(&el))
+            }
+            Modifier::Clamp { .. } => modifier.quote_clamp(quote!((*el))),
+            Modifier::EnsureScheme(scheme) => quote!(::validify::ensure_scheme(&el, #scheme)),
+            Modifier::ParseNumber { decimal_separator } => {
+                quote!(::validify::normalize_number(&el, #decimal_separator))
+            }
             _ => unreachable!("modifier is never wrapped"),
         };
 
@@ -300,6 +492,14 @@ impl FieldInfo {
         )
     }
 
+    /// Returns the conversion function and the payload type if the field is converted from the payload.
+    pub fn conversion(&self) -> Option<(&syn::Path, &syn::Type)> {
+        self.modifiers.iter().find_map(|modifier| match modifier {
+            Modifier::Convert { function, from } => Some((function, from.as_ref())),
+            _ => None,
+        })
+    }
+
     /// Return all the field's attributes that are unrelated to validify and serde
     pub fn remaining_attrs(&self) -> Vec<&syn::Attribute> {
         self.field
@@ -318,11 +518,16 @@ impl FieldInfo {
             .collect()
     }
 
+    /// Returns the field's `serde(default)`, if any.
+    pub fn serde_default(&self) -> Option<crate::serde::SerdeDefault> {
+        crate::serde::find_default(&self.field)
+    }
+
     // ASSERTION
 
     /// Returns true if the field is an option.
     pub fn is_option(&self) -> bool {
-        let syn::Type::Path(ref p) = self.field.ty else {
+        let syn::Type::Path(p) = strip_type(&self.field.ty) else {
             return false;
         };
 
@@ -345,6 +550,20 @@ impl FieldInfo {
         is_map(&self.field.ty)
     }
 
+    /// Returns true if the field is a `PhantomData`, `PhantomPinned` or `()`. Such fields hold no
+    /// value and are ignored by validation.
+    pub fn is_zero_sized(&self) -> bool {
+        match strip_type(&self.field.ty) {
+            syn::Type::Tuple(tuple) => tuple.elems.is_empty(),
+            syn::Type::Path(p) => p
+                .path
+                .segments
+                .last()
+                .is_some_and(|seg| seg.ident == "PhantomData" || seg.ident == "PhantomPinned"),
+            _ => false,
+        }
+    }
+
     /// Returns true if the field is annotated with `#[validify]`
     pub fn is_nested_validify(&self) -> bool {
         self.field
@@ -398,21 +617,21 @@ fn is_reference(ty: &syn::Type) -> bool {
         return is_reference(ty);
     }
 
-    matches!(ty, syn::Type::Reference(_))
+    matches!(strip_type(ty), syn::Type::Reference(_))
 }
 
-fn is_list(ty: &syn::Type) -> bool {
+pub fn is_list(ty: &syn::Type) -> bool {
     if let Some(ty) = try_extract_option(ty) {
         return is_list(ty);
     }
 
     // We consider arrays lists
-    if let syn::Type::Array(_) = ty {
+    if let syn::Type::Array(_) = strip_type(ty) {
         return true;
     }
 
     // If it's not a path, it's not a list
-    let syn::Type::Path(p) = ty else {
+    let syn::Type::Path(p) = strip_type(ty) else {
         return false;
     };
 
@@ -427,12 +646,12 @@ fn is_list(ty: &syn::Type) -> bool {
         || seg.ident == "IndexSet"
 }
 
-fn is_map(ty: &syn::Type) -> bool {
+pub fn is_map(ty: &syn::Type) -> bool {
     if let Some(ty) = try_extract_option(ty) {
         return is_map(ty);
     }
 
-    let syn::Type::Path(p) = ty else {
+    let syn::Type::Path(p) = strip_type(ty) else {
         return false;
     };
 
@@ -444,8 +663,21 @@ fn is_map(ty: &syn::Type) -> bool {
     seg.ident == "HashMap" || seg.ident == "BTreeMap" || seg.ident == "IndexMap"
 }
 
-fn try_extract_option(ty: &syn::Type) -> Option<&syn::Type> {
-    let syn::Type::Path(p) = ty else {
+/// Only the field's type is inspected when detecting its kind, never its attributes or visibility.
+/// Types passed through `macro_rules` fragments arrive wrapped in invisible groups and are
+/// unwrapped along with any parentheses.
+fn strip_type(mut ty: &syn::Type) -> &syn::Type {
+    loop {
+        match ty {
+            syn::Type::Group(group) => ty = &group.elem,
+            syn::Type::Paren(paren) => ty = &paren.elem,
+            _ => return ty,
+        }
+    }
+}
+
+pub fn try_extract_option(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(p) = strip_type(ty) else {
         return None;
     };
 
@@ -460,9 +692,7 @@ fn try_extract_option(ty: &syn::Type) -> Option<&syn::Type> {
         return None;
     };
 
-    let Some(arg) = ab.args.last() else {
-        return None;
-    };
+    let arg = ab.args.last()?;
 
     match arg {
         syn::GenericArgument::Type(ty) => Some(ty),
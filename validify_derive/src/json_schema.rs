@@ -0,0 +1,72 @@
+use crate::{
+    fields::FieldInfo,
+    validate::{
+        parser::ValueOrPath,
+        validation::{RangeBound, Validator},
+    },
+};
+use proc_macro2::TokenStream;
+use quote::quote;
+use serde_json::{json, Map, Value};
+
+/// Generates the `JSON_SCHEMA` constant containing the JSON schema keywords the field validators
+/// map to. Only literal arguments can be described, validators using paths are skipped.
+pub fn quote_json_schema(input: &syn::DeriveInput, fields: &[FieldInfo]) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let properties: Map<String, Value> = fields
+        .iter()
+        .filter_map(|field| {
+            let mut keywords = Map::new();
+            for validator in field.validations.iter() {
+                insert_keywords(validator, &mut keywords);
+            }
+            (!keywords.is_empty()).then(|| (field.name(), Value::Object(keywords)))
+        })
+        .collect();
+
+    let schema = json!({ "type": "object", "properties": properties }).to_string();
+
+    quote!(
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// JSON schema fragment describing the constraints of the struct's validators.
+            pub const JSON_SCHEMA: &'static str = #schema;
+        }
+    )
+}
+
+fn insert_keywords(validator: &Validator, keywords: &mut Map<String, Value>) {
+    match validator {
+        Validator::Email(_) => {
+            keywords.insert("format".to_string(), json!("email"));
+        }
+        // Patterns matched against a transformed value do not describe the value itself
+        Validator::Regex(v) if !v.transforms() => {
+            if let ValueOrPath::Value(ref pattern) = v.regex {
+                keywords.insert("pattern".to_string(), json!(pattern));
+            }
+        }
+        // Lengths not counting whitespace cannot be described by the schema keywords
+        Validator::Length(v) if !v.ignore_whitespace => {
+            for (key, bound) in [
+                ("minLength", &v.min),
+                ("maxLength", &v.max),
+                ("minLength", &v.equal),
+                ("maxLength", &v.equal),
+            ] {
+                if let Some(ValueOrPath::Value(bound)) = bound {
+                    keywords.insert(key.to_string(), json!(bound));
+                }
+            }
+        }
+        Validator::Range(v) => {
+            for (key, bound) in [("minimum", &v.min), ("maximum", &v.max)] {
+                if let Some(RangeBound::Value(bound)) = bound {
+                    keywords.insert(key.to_string(), json!(bound));
+                }
+            }
+        }
+        _ => {}
+    }
+}
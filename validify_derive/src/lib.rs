@@ -1,11 +1,19 @@
-use proc_macro_error::proc_macro_error;
+use proc_macro_error::{abort, proc_macro_error};
 use quote::{quote, ToTokens};
 use syn::{parse::Parse, ItemFn, LitStr, Token};
 
+mod builder;
+mod discriminants;
+#[cfg(test)]
+mod expand;
 mod fields;
+mod json_schema;
 mod payload;
+mod preset;
+mod rules;
 mod serde;
 mod tokens;
+mod track_changes;
 mod validate;
 mod validify;
 
@@ -80,7 +88,7 @@ mod validify;
 #[proc_macro_error]
 pub fn derive_validify(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let ast = syn::parse(input).unwrap();
-    validify::r#impl::impl_validify(&ast).into()
+    derive(VALIDIFY, &ast).into()
 }
 
 /// Derives `Validate` based on the provided field attributes.
@@ -140,7 +148,32 @@ pub fn derive_validify(input: proc_macro::TokenStream) -> proc_macro::TokenStrea
 #[proc_macro_error]
 pub fn derive_validate(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse(input).unwrap();
-    validate::r#impl::impl_validate(&input).into()
+    derive(VALIDATE, &input).into()
+}
+
+/// Exposes the discriminants of a fieldless enum through `validify::Discriminants`. Allows
+/// integer fields to be validated against the enum with `one_of(enum_discriminants = "Enum")`.
+///
+/// ```ignore
+/// #[derive(validify::Discriminants)]
+/// #[repr(u8)]
+/// enum Color {
+///     Red = 1,
+///     Green = 2,
+///     Blue = 4,
+/// }
+///
+/// #[derive(validify::Validate)]
+/// struct Pixel {
+///     #[validate(one_of(enum_discriminants = "Color"))]
+///     color: u8,
+/// }
+/// ```
+#[proc_macro_derive(Discriminants)]
+#[proc_macro_error]
+pub fn derive_discriminants(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = syn::parse(input).unwrap();
+    discriminants::impl_discriminants(&input).into()
 }
 
 /// A shortcut for ergonomic error creation in custom schema validator functions.
@@ -259,7 +292,41 @@ pub fn schema_validation(
 #[proc_macro_error]
 pub fn derive_payload(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = syn::parse(input).unwrap();
-    payload::r#impl::impl_payload(&input).into()
+    derive(PAYLOAD, &input).into()
+}
+
+/// Called by the macros defined with `validify::preset!` with the item that referenced them and
+/// the preset's attributes. Not part of the public API.
+#[doc(hidden)]
+#[proc_macro]
+#[proc_macro_error]
+pub fn __resolve_preset(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let preset::PresetCallback {
+        derive: derive_ident,
+        path,
+        mut input,
+        attrs,
+    } = syn::parse(input).unwrap_or_else(|e: syn::Error| abort!(e.span(), e));
+    preset::apply_preset(&mut input, &path, &attrs);
+    derive(&derive_ident.to_string(), &input).into()
+}
+
+const VALIDATE: &str = "Validate";
+const VALIDIFY: &str = "Validify";
+const PAYLOAD: &str = "Payload";
+
+/// Expands the derive once all the presets referenced by the fields are resolved.
+fn derive(derive: &str, input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    if let Some(preset) = preset::defer(derive, input) {
+        return preset;
+    }
+
+    match derive {
+        VALIDATE => validate::r#impl::impl_validate(input),
+        VALIDIFY => validify::r#impl::impl_validify(input),
+        PAYLOAD => payload::r#impl::impl_payload(input),
+        _ => unreachable!("presets only call back into validify derives"),
+    }
 }
 
 struct SchemaErr {
@@ -1,6 +1,6 @@
 use crate::{
     fields::FieldInfo,
-    serde::{extract_custom_serde, quote_custom_serde_payload_field},
+    serde::{extract_custom_serde, quote_custom_serde_payload_field, strip_default, SerdeDefault},
 };
 use proc_macro_error::abort;
 use quote::{format_ident, quote};
@@ -42,16 +42,60 @@ pub(super) fn generate_struct(input: &syn::DeriveInput) -> proc_macro2::TokenStr
 
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+    // Fields behind `cfg` are only mapped when they exist
+    let gate = |info: &FieldInfo, tokens: proc_macro2::TokenStream| {
+        let cfg_attrs = info.cfg_attrs();
+        quote!(#(#cfg_attrs)* #tokens)
+    };
+
     let into_fields = fields
         .iter()
-        .map(map_into_fields)
+        .map(|info| gate(info, map_into_fields(info)))
         .collect::<Vec<proc_macro2::TokenStream>>();
 
     let from_fields = fields
         .iter()
-        .map(map_from_fields)
+        .map(|info| gate(info, map_from_fields(info)))
+        .collect::<Vec<proc_macro2::TokenStream>>();
+
+    let conversions = fields
+        .iter()
+        .filter_map(|info| Some(gate(info, quote_conversion(info)?)))
         .collect::<Vec<proc_macro2::TokenStream>>();
 
+    // Converting fields can fail so we can only provide `TryFrom`
+    let from_payload = if conversions.is_empty() {
+        quote!(
+            impl #impl_generics From<#payload_ident> for #ident {
+                fn from(payload: #payload_ident) -> Self {
+                    Self {
+                        #(#from_fields)*
+                    }
+                }
+            }
+        )
+    } else {
+        quote!(
+            impl #impl_generics TryFrom<#payload_ident> for #ident {
+                type Error = ::validify::ValidationErrors;
+
+                fn try_from(payload: #payload_ident) -> Result<Self, Self::Error> {
+                    let mut errors = ::validify::ValidationErrors::new();
+
+                    #(#conversions)*
+
+                    if !errors.is_empty() {
+                        return Err(errors);
+                    }
+
+                    Ok(Self {
+                        #(#from_fields)*
+                    })
+                }
+            }
+        )
+    };
+
     let quoted = quote!(
         #[derive(Debug, ::validify::Validate, serde::Deserialize)]
         #(#attributes)*
@@ -67,13 +111,7 @@ pub(super) fn generate_struct(input: &syn::DeriveInput) -> proc_macro2::TokenStr
             }
         }
 
-        impl #impl_generics From<#payload_ident> for #ident {
-            fn from(payload: #payload_ident) -> Self {
-                Self {
-                    #(#from_fields)*
-                }
-            }
-        }
+        #from_payload
 
         #(#custom_serdes)*
     );
@@ -96,6 +134,55 @@ fn map_payload_fields(
     let serde_attrs = info.serde_attrs();
     let (custom_serde, serde_attrs) = extract_custom_serde(&serde_attrs);
 
+    if let Some((_, from)) = info.conversion() {
+        if custom_serde.is_some() {
+            abort!(
+                info.field.span(),
+                "Custom deserialization cannot be used on fields converted from payloads"
+            )
+        }
+
+        let remaining_attrs = info.remaining_attrs();
+        let required = (!is_option).then(|| quote!(#[validate(required)]));
+
+        return (
+            quote!(
+                #(#serde_attrs)*
+                #(#remaining_attrs)*
+                #required
+                #ident: Option<#from>,
+            ),
+            None,
+        );
+    }
+
+    // Zero sized fields are not part of the payload and are defaulted when deserializing
+    if info.is_zero_sized() {
+        let remaining_attrs = info.remaining_attrs();
+        return (
+            quote!(
+                #(#remaining_attrs)*
+                #[serde(skip)]
+                #ident: #ty,
+            ),
+            None,
+        );
+    }
+
+    // Fields with defaults are not required in the payload, the default gets applied when
+    // converting to the original
+    let default = (!is_option).then(|| info.serde_default()).flatten();
+    let serde_attrs = if default.is_some() {
+        strip_default(&serde_attrs)
+    } else {
+        serde_attrs.into_iter().cloned().collect()
+    };
+    let required = if default.is_some() {
+        quote!(#[serde(default)])
+    } else {
+        quote!(#[validate(required)])
+    };
+
     let mut custom_de_attr = None;
     let mut custom_de_tokens = None;
 
@@ -116,7 +203,7 @@ fn map_payload_fields(
                 #custom_de_attr
                 #(#serde_attrs)*
                 #(#remaining_attrs)*
-                #[validate(required)]
+                #required
                 #ident: Option<#ty>,
             ),
             custom_de_tokens,
@@ -148,7 +235,7 @@ fn map_payload_fields(
                 #custom_de_attr
                 #(#serde_attrs)*
                 #(#remaining_attrs)*
-                #[validate(required)]
+                #required
                 #[validate]
                 #ident: Option<#payload_type>,
             ),
@@ -208,9 +295,64 @@ fn map_payload_fields(
     )
 }
 
+/// Quotes the conversion of the payload field to the original one. The result is bound to a variable
+/// with the field's name, any errors are added to the `errors`.
+fn quote_conversion(info: &FieldInfo) -> Option<proc_macro2::TokenStream> {
+    let (function, _) = info.conversion()?;
+    let ident = info.field.ident.as_ref().unwrap();
+    let field_name = info.name();
+
+    let handle_err = quote!(
+        if let Some(field_name) = err.field_name().map(|s| s.to_string()) {
+            err.set_location(field_name);
+        } else {
+            err.set_field(#field_name);
+            err.set_location(#field_name);
+        }
+        errors.add(err);
+    );
+
+    // Required fields missing from the payload fail the conversion instead of panicking, since
+    // the payload is not necessarily validated before being converted
+    let handle_none = if info.is_option() {
+        quote!()
+    } else {
+        quote!(
+            let mut err = ::validify::ValidationError::new_field_named(#field_name, "required");
+            err.set_location(#field_name);
+            errors.add(err);
+        )
+    };
+
+    Some(quote!(
+        let #ident = match payload.#ident.map(#function) {
+            Some(Ok(value)) => Some(value),
+            Some(Err(mut err)) => {
+                #handle_err
+                None
+            }
+            None => {
+                #handle_none
+                None
+            }
+        };
+    ))
+}
+
 fn map_from_fields(info: &FieldInfo) -> proc_macro2::TokenStream {
     let ident = info.field.ident.as_ref().unwrap();
 
+    if info.is_zero_sized() {
+        return quote!(#ident: payload.#ident,);
+    }
+
+    if info.conversion().is_some() {
+        if info.is_option() {
+            return quote!(#ident,);
+        }
+        return quote!(#ident: #ident.unwrap(),);
+    }
+
     if info.is_option() {
         if info.is_nested_validify() && info.is_list() {
             return quote!(
@@ -228,21 +370,40 @@ fn map_from_fields(info: &FieldInfo) -> proc_macro2::TokenStream {
             #ident: payload.#ident,
         )
     } else {
+        let unwrap = match info.serde_default() {
+            Some(SerdeDefault::Default) => quote!(unwrap_or_default()),
+            Some(SerdeDefault::Path(path)) => quote!(unwrap_or_else(#path)),
+            None => quote!(unwrap()),
+        };
+
         if info.is_nested_validify() && info.is_list() {
-            return quote!(#ident: payload.#ident.unwrap().into_iter().map(|el|el.into()).collect(),);
+            return quote!(
+                #ident: payload.#ident.map(|v|v.into_iter().map(|el|el.into()).collect()).#unwrap,
+            );
         }
 
         if info.is_nested_validify() {
-            return quote!(#ident: payload.#ident.unwrap().into(),);
+            return quote!(#ident: payload.#ident.map(|o|o.into()).#unwrap,);
         }
 
-        quote!(#ident: payload.#ident.unwrap(),)
+        quote!(#ident: payload.#ident.#unwrap,)
     }
 }
 
 fn map_into_fields(info: &FieldInfo) -> proc_macro2::TokenStream {
     let ident = info.field.ident.as_ref().unwrap();
 
+    if info.is_zero_sized() {
+        return quote!(#ident: original.#ident,);
+    }
+
+    if info.conversion().is_some() {
+        if info.is_option() {
+            return quote!(#ident: original.#ident.map(Into::into),);
+        }
+        return quote!(#ident: Some(original.#ident.into()),);
+    }
+
     if info.is_option() {
         if info.is_nested_validify() && info.is_list() {
             return quote!(#ident: original.#ident.map(|v| v.into_iter().map(|el|el.into()).collect()),);
@@ -6,6 +6,16 @@ pub fn impl_payload(input: &DeriveInput) -> proc_macro2::TokenStream {
     let strct = super::generate_struct(input);
     let payload_id = super::payload_ident(ident);
 
+    // Payloads with converted fields can only be fallibly converted to the original
+    let has_conversions = crate::fields::FieldInfo::collect(input)
+        .iter()
+        .any(|field| field.conversion().is_some());
+    let from_payload = if has_conversions {
+        quote!(#ident::try_from(payload)?)
+    } else {
+        quote!(#ident::from(payload))
+    };
+
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     quote!(
@@ -18,7 +28,7 @@ pub fn impl_payload(input: &DeriveInput) -> proc_macro2::TokenStream {
             {
                 <Self::Payload as ::validify::Validate>::validate(&payload)?;
 
-                let mut this = #ident ::from(payload);
+                let mut this = #from_payload;
 
                 let mut errors = ::validify::ValidationErrors::new();
 
@@ -37,7 +47,7 @@ pub fn impl_payload(input: &DeriveInput) -> proc_macro2::TokenStream {
             {
                 <Self::Payload as ::validify::Validate>::validate(&payload)?;
 
-                let mut this = #ident ::from(payload);
+                let mut this = #from_payload;
 
                 let mut errors = ::validify::ValidationErrors::new();
 
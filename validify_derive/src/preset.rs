@@ -0,0 +1,319 @@
+use proc_macro2::TokenStream;
+use proc_macro_error::abort;
+use quote::{quote, ToTokens};
+use syn::{punctuated::Punctuated, spanned::Spanned, Attribute, LitStr, Meta, Token};
+
+const PRESET: &str = "preset";
+const VALIDATE: &str = "validate";
+const MODIFY: &str = "modify";
+const APPLY: &str = "apply";
+
+/// Returns the invocation of the first preset referenced by the fields, if any.
+///
+/// Presets are defined with `validify::preset!` as macros, which derives cannot expand. Instead,
+/// the derive invokes the preset with the item and the preset calls back into `__resolve_preset`
+/// with its attributes. The attributes are substituted and the derive runs again until no presets
+/// remain.
+pub fn defer(derive: &str, input: &syn::DeriveInput) -> Option<TokenStream> {
+    let syn::Data::Struct(syn::DataStruct { ref fields, .. }) = input.data else {
+        return None;
+    };
+
+    let path = fields
+        .iter()
+        .flat_map(|field| field.attrs.iter())
+        .filter_map(preset_metas)
+        .flatten()
+        .find_map(|meta| (meta.path().is_ident(PRESET)).then(|| preset_path(&meta)))?;
+
+    let derive = syn::Ident::new(derive, proc_macro2::Span::call_site());
+
+    Some(quote!(#path! { { #derive #path #input } }))
+}
+
+/// The item a preset calls back with, followed by the attributes of the preset.
+pub struct PresetCallback {
+    pub derive: syn::Ident,
+    pub path: syn::Path,
+    pub input: syn::DeriveInput,
+    pub attrs: Vec<Attribute>,
+}
+
+impl syn::parse::Parse for PresetCallback {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let item;
+        syn::braced!(item in input);
+        let derive = item.parse()?;
+        let path = item.parse()?;
+        let derive_input = item.parse()?;
+
+        let preset;
+        syn::braced!(preset in input);
+        let metas = Punctuated::<Meta, Token![,]>::parse_terminated(&preset)?;
+
+        let mut attrs = vec![];
+        for meta in metas {
+            match meta {
+                Meta::List(ref list)
+                    if list.path.is_ident(VALIDATE) || list.path.is_ident(MODIFY) =>
+                {
+                    attrs.push(syn::parse_quote!(#[#meta]))
+                }
+                _ => {
+                    return Err(syn::Error::new(
+                        meta.span(),
+                        "Unrecognized preset parameter, accepted are: validate, modify",
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            derive,
+            path,
+            input: derive_input,
+            attrs,
+        })
+    }
+}
+
+/// Replaces every `preset = path` of the fields with the attributes of the preset, keeping the
+/// validators declared before and after it in their order.
+pub fn apply_preset(input: &mut syn::DeriveInput, path: &syn::Path, preset: &[Attribute]) {
+    let syn::Data::Struct(syn::DataStruct { ref mut fields, .. }) = input.data else {
+        return;
+    };
+
+    let path = path.to_token_stream().to_string();
+
+    for field in fields.iter_mut() {
+        let mut attrs = vec![];
+
+        for attr in field.attrs.drain(..) {
+            // Only rebuild the attribute if it references the preset so any errors are reported
+            // by the validation parser with their original spans
+            let metas = match preset_metas(&attr) {
+                Some(metas)
+                    if metas.iter().any(|meta| {
+                        meta.path().is_ident(PRESET)
+                            && preset_path(meta).to_token_stream().to_string() == path
+                    }) =>
+                {
+                    metas
+                }
+                _ => {
+                    attrs.push(attr);
+                    continue;
+                }
+            };
+
+            let mut pending: Vec<Meta> = vec![];
+
+            for meta in metas {
+                if !meta.path().is_ident(PRESET)
+                    || preset_path(&meta).to_token_stream().to_string() != path
+                {
+                    pending.push(meta);
+                    continue;
+                }
+
+                if !pending.is_empty() {
+                    attrs.push(syn::parse_quote!(#[validate(#(#pending),*)]));
+                    pending.clear();
+                }
+                attrs.extend(preset.iter().cloned());
+            }
+
+            if !pending.is_empty() {
+                attrs.push(syn::parse_quote!(#[validate(#(#pending),*)]));
+            }
+        }
+
+        field.attrs = attrs;
+    }
+}
+
+/// Returns the metas of a `#[validate(..)]` attribute if it references a preset.
+fn preset_metas(attr: &Attribute) -> Option<Punctuated<Meta, Token![,]>> {
+    let Meta::List(ref list) = attr.meta else {
+        return None;
+    };
+
+    if !attr.path().is_ident(VALIDATE) {
+        return None;
+    }
+
+    match list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) {
+        Ok(metas) if metas.iter().any(|meta| meta.path().is_ident(PRESET)) => Some(metas),
+        _ => None,
+    }
+}
+
+/// Presets are referenced by the path to their macro, directly or as a string literal, e.g.
+/// `preset = username` or `preset = "crate::presets::username"`.
+fn preset_path(meta: &Meta) -> syn::Path {
+    let Meta::NameValue(syn::MetaNameValue { ref value, .. }) = meta else {
+        abort!(
+            meta.span(),
+            "preset must be specified as a path to a preset, i.e. `preset = username`"
+        )
+    };
+
+    match value {
+        syn::Expr::Path(syn::ExprPath { path, .. }) => path.clone(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit),
+            ..
+        }) => lit.parse().unwrap_or_else(|e| abort!(lit.span(), e)),
+        _ => abort!(
+            value.span(),
+            "preset must be specified as a path to a preset, i.e. `preset = username`"
+        ),
+    }
+}
+
+/// Validators shared by multiple fields, defined on the struct with
+/// `#[validate(apply(length(max = 255), to = ["a", "b"]))]`.
+#[derive(Debug)]
+pub struct SharedRule {
+    attr: Attribute,
+    fields: Vec<LitStr>,
+}
+
+/// Collects the shared rules defined on the struct and checks that the fields they are applied to
+/// exist and can hold them.
+pub fn collect_shared_rules(attrs: &[Attribute], fields: &syn::Fields) -> Vec<SharedRule> {
+    let mut rules = vec![];
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(VALIDATE)) {
+        // Other struct level options are parsed by the validation parser
+        let _ = attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident(APPLY) {
+                if meta.input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    content.parse::<proc_macro2::TokenStream>()?;
+                } else if meta.input.peek(Token![=]) {
+                    meta.value()?.parse::<syn::Expr>()?;
+                }
+                return Ok(());
+            }
+
+            rules.push(parse_shared_rule(&meta).unwrap_or_else(|e| abort!(e.span(), e)));
+            Ok(())
+        });
+    }
+
+    for rule in rules.iter() {
+        for name in rule.fields.iter() {
+            let Some(field) = fields
+                .iter()
+                .find(|field| field.ident.as_ref().is_some_and(|id| *id == name.value()))
+            else {
+                abort!(
+                    name.span(),
+                    format!("Field `{}` does not exist on the struct", name.value())
+                )
+            };
+            check_shared_rule(rule, field, name);
+        }
+    }
+
+    rules
+}
+
+fn parse_shared_rule(meta: &syn::meta::ParseNestedMeta) -> Result<SharedRule, syn::Error> {
+    let mut validations = vec![];
+    let mut fields = vec![];
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("to") {
+            let content = meta.value()?;
+            let list;
+            syn::bracketed!(list in content);
+            fields.extend(list.parse_terminated(<LitStr as syn::parse::Parse>::parse, Token![,])?);
+            if fields.is_empty() {
+                return Err(meta.error("to must contain at least one field"));
+            }
+            return Ok(());
+        }
+
+        let path = &meta.path;
+        if meta.input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in meta.input);
+            let tokens: proc_macro2::TokenStream = content.parse()?;
+            validations.push(quote::quote!(#path(#tokens)));
+        } else if meta.input.peek(Token![=]) {
+            let value: syn::Expr = meta.value()?.parse()?;
+            validations.push(quote::quote!(#path = #value));
+        } else {
+            validations.push(quote::quote!(#path));
+        }
+        Ok(())
+    })?;
+
+    if validations.is_empty() {
+        return Err(meta.error("apply must contain at least one validator"));
+    }
+
+    if fields.is_empty() {
+        return Err(meta.error("apply must specify the fields it applies to, i.e. `to = [\"..\"]`"));
+    }
+
+    Ok(SharedRule {
+        attr: syn::parse_quote!(#[validate(#(#validations),*)]),
+        fields,
+    })
+}
+
+/// Collection validators can only be shared by fields of a matching type, any other mismatch is
+/// reported by the compiler on the shared rule.
+fn check_shared_rule(rule: &SharedRule, field: &syn::Field, name: &LitStr) {
+    let Meta::List(ref list) = rule.attr.meta else {
+        return;
+    };
+    let Ok(metas) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) else {
+        return;
+    };
+
+    for meta in metas.iter() {
+        let compatible = if meta.path().is_ident("iter") {
+            crate::fields::is_list(&field.ty)
+        } else if meta.path().is_ident("values") || meta.path().is_ident("each_entry") {
+            crate::fields::is_map(&field.ty) || crate::fields::is_list(&field.ty)
+        } else if meta.path().is_ident("each") {
+            matches!(field.ty, syn::Type::Tuple(_))
+        } else {
+            true
+        };
+
+        if !compatible {
+            abort!(
+                name.span(),
+                format!(
+                    "`{}` cannot be applied to field `{}` of an incompatible type",
+                    meta.path().to_token_stream(),
+                    name.value()
+                )
+            )
+        }
+    }
+}
+
+/// Returns the field with the shared rules applying to it appended to its attributes.
+pub fn apply_shared_rules(field: syn::Field, rules: &[SharedRule]) -> syn::Field {
+    let mut field = field;
+    let Some(ref ident) = field.ident else {
+        return field;
+    };
+
+    let attrs: Vec<Attribute> = rules
+        .iter()
+        .filter(|rule| rule.fields.iter().any(|name| *ident == name.value()))
+        .map(|rule| rule.attr.clone())
+        .collect();
+
+    field.attrs.extend(attrs);
+    field
+}
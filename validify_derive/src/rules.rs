@@ -0,0 +1,401 @@
+use crate::{
+    fields::FieldInfo,
+    validate::{
+        parser::ValueOrPath,
+        validation::{IpFormat, RangeBound, TimeOp, Validator},
+    },
+    validify::modifier::{HashAlgo, Modifier},
+};
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// Generates the `rules` function describing the validators and modifiers of each field.
+pub fn quote_rules(input: &syn::DeriveInput, fields: &[FieldInfo]) -> TokenStream {
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Zero sized fields have no value to describe
+    let field_rules = fields
+        .iter()
+        .filter(|field| !field.is_zero_sized())
+        .map(|field| {
+            let name = field.name();
+            // Validators of tagged fields only run for the listed variants
+            let variants = &field.variants;
+            let validators = field
+                .validations
+                .iter()
+                .flat_map(|validator| quote_validator_rules(validator, None))
+                .map(|rule| match variants.is_empty() {
+                    true => rule,
+                    false => quote!(#rule.with_param("variants", &[#(#variants),*])),
+                });
+            let modifiers = field.modifiers.iter().map(quote_modifier_rule);
+            let cfg_attrs = field.cfg_attrs();
+            quote!(
+                #(#cfg_attrs)*
+                rules.insert(
+                    ::std::string::String::from(#name),
+                    ::std::vec![#(#validators,)* #(#modifiers,)*],
+                );
+            )
+        });
+
+    quote!(
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Returns the validators and modifiers applied to each field, keyed by the field names
+            /// used in errors.
+            pub fn rules() -> ::std::collections::HashMap<::std::string::String, ::std::vec::Vec<::validify::RuleInfo>> {
+                let mut rules = ::std::collections::HashMap::new();
+                #(#field_rules)*
+                rules
+            }
+        }
+    )
+}
+
+/// Validators in `iter`, `values`, `each`, `each_entry` and `warn` are flattened into the field's
+/// rules and marked with an `iter`, `values`, `each`, `each_entry_key`, `each_entry_value` or
+/// `warning` param.
+fn quote_validator_rules(validator: &Validator, collection: Option<&str>) -> Vec<TokenStream> {
+    let (name, mut params): (&str, Vec<(&str, TokenStream)>) = match validator {
+        Validator::Iter(validators) => {
+            return validators
+                .iter()
+                .flat_map(|v| quote_validator_rules(v, Some("iter")))
+                .collect()
+        }
+        Validator::Values(validators) => {
+            return validators
+                .iter()
+                .flat_map(|v| quote_validator_rules(v, Some("values")))
+                .collect()
+        }
+        Validator::Each(validators) => {
+            return validators
+                .iter()
+                .flat_map(|v| quote_validator_rules(v, Some("each")))
+                .collect()
+        }
+        Validator::Warn(validators) => {
+            return validators
+                .iter()
+                .flat_map(|v| quote_validator_rules(v, Some("warning")))
+                .collect()
+        }
+        Validator::EachEntry { key, value } => {
+            return key
+                .iter()
+                .flat_map(|v| quote_validator_rules(v, Some("each_entry_key")))
+                .chain(
+                    value
+                        .iter()
+                        .flat_map(|v| quote_validator_rules(v, Some("each_entry_value"))),
+                )
+                .collect()
+        }
+        Validator::Email(v) => {
+            let params = if v.require_tld {
+                vec![("require_tld", quote!(true))]
+            } else {
+                vec![]
+            };
+            ("email", params)
+        }
+        Validator::Finite(_) => ("finite", vec![]),
+        Validator::TryInto(v) => {
+            let ty = v.ty.to_token_stream().to_string().replace(' ', "");
+            ("try_into", vec![("type", quote!(#ty))])
+        }
+        Validator::ValidRange(_) => ("valid_range", vec![]),
+        Validator::RangeLen(v) => (
+            "range_len",
+            [("min", v.min), ("max", v.max)]
+                .into_iter()
+                .filter_map(|(key, bound)| {
+                    let bound = bound?;
+                    Some((key, quote!(#bound)))
+                })
+                .collect(),
+        ),
+        Validator::Url(_) => ("url", vec![]),
+        // Described by the rule of the `parse_number` modifier it is added for
+        Validator::ParseNumber(_) => return vec![],
+        Validator::CreditCard(_) => ("credit_card", vec![]),
+        Validator::Phone(_) => ("phone", vec![]),
+        Validator::NonControlCharacter(_) => ("non_control_char", vec![]),
+        Validator::Required(_) => ("required", vec![]),
+        Validator::Requires(v) => {
+            let fields = v.fields.iter().map(|field| field.value());
+            ("requires", vec![("fields", quote!([#(#fields),*]))])
+        }
+        Validator::Custom(v) => {
+            let mut params = vec![("function", path_str(&v.path))];
+            if let Some(timeout_ms) = v.timeout_ms {
+                params.push(("timeout_ms", quote!(#timeout_ms)));
+            }
+            if !v.groups.is_empty() {
+                let groups = &v.groups;
+                params.push(("groups", quote!([#(#groups),*])));
+            }
+            if v.run_last {
+                params.push(("run_last", quote!(true)));
+            }
+            if v.by_value {
+                params.push(("by_value", quote!(true)));
+            }
+            ("custom", params)
+        }
+        Validator::Range(v) => {
+            let mut params: Vec<_> = [("min", &v.min), ("max", &v.max)]
+                .into_iter()
+                .filter_map(|(key, bound)| Some((key, range_bound(bound.as_ref()?))))
+                .collect();
+            if let Some(ref step) = v.step {
+                params.push(("step", value_or_path(step)));
+            }
+            ("range", params)
+        }
+        Validator::Length(v) => {
+            let mut params: Vec<_> = [("min", &v.min), ("max", &v.max), ("equal", &v.equal)]
+                .into_iter()
+                .filter_map(|(key, bound)| Some((key, value_or_path(bound.as_ref()?))))
+                .collect();
+            params.extend(
+                [
+                    ("min_from", &v.min_from),
+                    ("max_from", &v.max_from),
+                    ("equal_from", &v.equal_from),
+                ]
+                .into_iter()
+                .filter_map(|(key, path)| Some((key, path_str(path.as_ref()?)))),
+            );
+            if v.none_is_zero {
+                params.push(("none_is_zero", quote!(true)));
+            }
+            if v.ignore_whitespace {
+                params.push(("ignore_whitespace", quote!(true)));
+            }
+            ("length", params)
+        }
+        Validator::MustMatch(v) => {
+            let value = v.value.to_string();
+            ("must_match", vec![("value", quote!(#value))])
+        }
+        Validator::Regex(v) => {
+            let mut params = vec![("pattern", value_or_path(&v.regex))];
+            if v.lowercase_first {
+                params.push(("lowercase_first", quote!(true)));
+            }
+            if v.trim_first {
+                params.push(("trim_first", quote!(true)));
+            }
+            ("regex", params)
+        }
+        Validator::EachLine(v) => (
+            "each_line",
+            v.regex
+                .as_ref()
+                .map(|regex| vec![("pattern", value_or_path(&regex.regex))])
+                .unwrap_or_default(),
+        ),
+        Validator::Contains(v) => (
+            if v.not { "contains_not" } else { "contains" },
+            v.value
+                .as_ref()
+                .map(|value| vec![("value", value_or_path(value))])
+                .unwrap_or_default(),
+        ),
+        Validator::ContainsWord(v) => (
+            "contains_word",
+            v.value
+                .as_ref()
+                .map(|value| vec![("value", value_or_path(value))])
+                .unwrap_or_default(),
+        ),
+        Validator::Time(v) => {
+            let op = match v.op {
+                TimeOp::BeforeNow => "before_now",
+                TimeOp::AfterNow => "after_now",
+                TimeOp::Before => "before",
+                TimeOp::After => "after",
+                TimeOp::BeforeFromNow => "before_from_now",
+                TimeOp::AfterFromNow => "after_from_now",
+                TimeOp::InPeriod => "in_period",
+                TimeOp::None => unreachable!(),
+            };
+            let mut params = vec![("op", quote!(#op))];
+            if let Some(ref target) = v.target {
+                params.push(("target", value_or_path(target)));
+            }
+            if let Some(ref duration) = v.duration {
+                params.push(("duration", value_or_path(duration)));
+            }
+            if let Some(ref format) = v.format {
+                params.push(("format", quote!(#format)));
+            }
+            if v.inclusive {
+                params.push(("inclusive", quote!(true)));
+            }
+            ("time", params)
+        }
+        Validator::In(v) => {
+            let expr = v
+                .expr
+                .as_ref()
+                .map(|expr| expr.to_token_stream().to_string())
+                .unwrap_or_default();
+            (
+                if v.not { "not_in" } else { "is_in" },
+                vec![("collection", quote!(#expr))],
+            )
+        }
+        Validator::OneOf(v) => {
+            let params = match v.enum_discriminants {
+                Some(ref path) => vec![("enum_discriminants", path_str(path))],
+                None => {
+                    let values = &v.values;
+                    let mut params = vec![("values", quote!([#(#values),*]))];
+                    if v.case_insensitive {
+                        params.push(("case_insensitive", quote!(true)));
+                    }
+                    params
+                }
+            };
+            ("one_of", params)
+        }
+        Validator::Ip(v) => {
+            let params = match v.format {
+                Some(IpFormat::V4) => vec![("format", quote!("v4"))],
+                Some(IpFormat::V6) => vec![("format", quote!("v6"))],
+                None => vec![],
+            };
+            ("ip", params)
+        }
+        Validator::Future(v) => {
+            let grace = v.grace;
+            ("future", vec![("grace", quote!(#grace))])
+        }
+        Validator::Past(v) => {
+            let grace = v.grace;
+            ("past", vec![("grace", quote!(#grace))])
+        }
+        Validator::Nested => ("nested", vec![]),
+    };
+
+    if let Some(code) = validator_code(validator) {
+        params.push(("code", quote!(#code)));
+    }
+
+    if let Some(collection) = collection {
+        params.push((collection, quote!(true)));
+    }
+
+    let params = params
+        .into_iter()
+        .map(|(key, value)| quote!(.with_param(#key, &#value)));
+
+    vec![quote!(::validify::RuleInfo::validator(#name) #(#params)*)]
+}
+
+fn quote_modifier_rule(modifier: &Modifier) -> TokenStream {
+    let params: Vec<(&str, TokenStream)> = match modifier {
+        Modifier::Grouped { modifier, groups } => {
+            let rule = quote_modifier_rule(modifier);
+            return quote!(#rule.with_param("groups", &[#(#groups),*]));
+        }
+        Modifier::Trim | Modifier::Uppercase | Modifier::Lowercase | Modifier::Capitalize => {
+            vec![]
+        }
+        Modifier::Custom {
+            function,
+            control_flow,
+        } => {
+            let mut params = vec![("function", path_str(function))];
+            if *control_flow {
+                params.push(("control_flow", quote!(true)));
+            }
+            params
+        }
+        Modifier::Hash {
+            algo,
+            post_validate,
+        } => {
+            let algo = match algo {
+                HashAlgo::Sha256 => "sha256",
+                HashAlgo::Sha512 => "sha512",
+            };
+            let mut params = vec![("algo", quote!(#algo))];
+            if *post_validate {
+                params.push(("post_validate", quote!(true)));
+            }
+            params
+        }
+        Modifier::Clamp { min, max } => [("min", min), ("max", max)]
+            .into_iter()
+            .filter_map(|(key, bound)| {
+                let bound = bound.as_ref()?;
+                Some((key, quote!(#bound)))
+            })
+            .collect(),
+        Modifier::Convert { function, from } => {
+            let from = from.to_token_stream().to_string().replace(' ', "");
+            vec![("function", path_str(function)), ("from", quote!(#from))]
+        }
+        Modifier::Map { function, .. } => vec![("function", path_str(function))],
+        Modifier::EnsureScheme(scheme) => vec![("scheme", quote!(#scheme))],
+        Modifier::ParseNumber { decimal_separator } => {
+            let decimal_separator = decimal_separator.to_string();
+            vec![("decimal_separator", quote!(#decimal_separator))]
+        }
+        Modifier::Nested => vec![],
+    };
+
+    let name = modifier.name();
+    let params = params
+        .into_iter()
+        .map(|(key, value)| quote!(.with_param(#key, &#value)));
+
+    quote!(::validify::RuleInfo::modifier(#name) #(#params)*)
+}
+
+/// Returns the error code of the validator if it produces exactly one.
+fn validator_code(validator: &Validator) -> Option<String> {
+    match validator {
+        Validator::Custom(_)
+        | Validator::Nested
+        | Validator::Iter(_)
+        | Validator::Values(_)
+        | Validator::Each(_)
+        | Validator::Warn(_)
+        | Validator::EachEntry { .. } => None,
+        _ => validator.codes().pop(),
+    }
+}
+
+/// Literals are kept as they are, paths are described by their string representation.
+fn value_or_path<T: ToTokens>(value: &ValueOrPath<T>) -> TokenStream {
+    match value {
+        ValueOrPath::Value(value) => quote!(#value),
+        ValueOrPath::Path(path) => path_str(path),
+    }
+}
+
+/// Bounds relative to the current time are described as `now`, followed by the offset if any.
+fn range_bound(bound: &RangeBound) -> TokenStream {
+    match bound {
+        RangeBound::Value(value) => quote!(#value),
+        RangeBound::Path(path) => path_str(path),
+        RangeBound::Char(c) => quote!(#c),
+        RangeBound::Now(0) => quote!("now"),
+        RangeBound::Now(offset) => {
+            let now = format!("now{offset:+}");
+            quote!(#now)
+        }
+    }
+}
+
+fn path_str(path: &syn::Path) -> TokenStream {
+    let path = path.to_token_stream().to_string().replace(' ', "");
+    quote!(#path)
+}
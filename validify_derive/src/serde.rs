@@ -1,5 +1,5 @@
 use quote::{format_ident, quote};
-use syn::{parenthesized, punctuated::Punctuated, Attribute, Expr, Ident, Path, Token};
+use syn::{parenthesized, punctuated::Punctuated, Attribute, Expr, Ident, Meta, Path, Token};
 
 /// Represents whether custom serde comes from `deserialize_with` or just `with`.
 #[derive(Debug)]
@@ -106,6 +106,88 @@ pub fn extract_custom_serde<'a>(
     (custom_fn, rest)
 }
 
+/// Represents a field's `serde(default)` attribute.
+#[derive(Debug)]
+pub enum SerdeDefault {
+    /// `default`
+    Default,
+    /// `default = "path"`
+    Path(Path),
+}
+
+/// Attempts to find `serde(default)` or `serde(default = "..")` on the field.
+pub fn find_default(field: &syn::Field) -> Option<SerdeDefault> {
+    let mut default = None;
+
+    for attr in field.attrs.iter() {
+        if !attr.path().is_ident("serde") {
+            continue;
+        }
+
+        let Ok(metas) = attr.meta.require_list() else {
+            continue;
+        };
+
+        let Ok(metas) = metas.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        else {
+            continue;
+        };
+
+        for meta in metas {
+            match meta {
+                Meta::Path(ref path) if path.is_ident("default") => {
+                    default = Some(SerdeDefault::Default)
+                }
+                Meta::NameValue(ref nv) if nv.path.is_ident("default") => {
+                    let Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(ref lit),
+                        ..
+                    }) = nv.value
+                    else {
+                        continue;
+                    };
+                    if let Ok(path) = lit.parse::<Path>() {
+                        default = Some(SerdeDefault::Path(path));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    default
+}
+
+/// Returns the serde attributes without any `default` in them. Attributes that only contained
+/// `default` are omitted.
+pub fn strip_default(serde_attrs: &[&Attribute]) -> Vec<Attribute> {
+    let mut stripped = vec![];
+
+    for attr in serde_attrs {
+        let Ok(list) = attr.meta.require_list() else {
+            stripped.push((*attr).clone());
+            continue;
+        };
+
+        let Ok(metas) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+        else {
+            stripped.push((*attr).clone());
+            continue;
+        };
+
+        let rest = metas
+            .into_iter()
+            .filter(|meta| !meta.path().is_ident("default"))
+            .collect::<Vec<_>>();
+
+        if !rest.is_empty() {
+            stripped.push(syn::parse_quote!(#[serde(#(#rest),*)]));
+        }
+    }
+
+    stripped
+}
+
 /// Attempts to find serde's `serde(rename_all = "..")` attribute and returns the specified rename rule.
 pub fn find_rename_all(attrs: &[syn::Attribute]) -> Option<RenameRule> {
     let mut rule = None;
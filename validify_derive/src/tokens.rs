@@ -1,10 +1,12 @@
 use crate::fields::FieldInfo;
 use crate::validate::parser::ValueOrPath;
 use crate::validate::validation::{
-    Contains, CreditCard, Custom, Describe, Email, In, Ip, Length, MustMatch, NonControlChar,
-    Phone, Range, Regex, Required, SchemaValidation, Time, TimeMultiplier, Url, Validator,
+    Contains, ContainsWord, CreditCard, Custom, Describe, EachLine, Email, Finite, Future, In, Ip,
+    Length, MustMatch, NonControlChar, OneOf, ParseNumber, Past, Phone, PredicateValidation,
+    ProfileSink, Range, RangeLen, Regex, RegexLiteral, Required, Requires, SchemaValidation, Time,
+    TimeMultiplier, TryConvert, Url, ValidRange, Validator,
 };
-use proc_macro2::{self, TokenStream};
+use proc_macro2::{self, Span, TokenStream};
 use proc_macro_error::abort;
 use quote::quote;
 use syn::spanned::Spanned;
@@ -41,20 +43,31 @@ macro_rules! impl_error {
 }
 
 impl_error! {
+    Future,
+    Past,
     Length,
     Range,
+    Finite,
+    TryConvert,
+    ValidRange,
+    RangeLen,
     Email,
     Url,
+    ParseNumber,
     CreditCard,
     Phone,
     Custom,
     NonControlChar,
     Required,
+    Requires,
     MustMatch,
     Regex,
+    EachLine,
     Contains,
+    ContainsWord,
     Time,
     In,
+    OneOf,
     Ip
 }
 
@@ -79,32 +92,98 @@ pub fn quote_schema_validations(validation: &[SchemaValidation]) -> Vec<proc_mac
         .collect()
 }
 
-/// Output the necessary tokens for field validations when implementing `Validate`.
-pub fn quote_field_validations(fields: Vec<FieldInfo>) -> Vec<proc_macro2::TokenStream> {
+pub fn quote_predicate_validations(
+    predicates: &[PredicateValidation],
+) -> Vec<proc_macro2::TokenStream> {
+    predicates
+        .iter()
+        .map(|predicate| {
+            let function = &predicate.function;
+            let code = predicate.code();
+            let message = predicate
+                .message
+                .as_ref()
+                .map(|message| quote!(.with_message(::std::string::String::from(#message))));
+            quote!(
+                if !#function(self) {
+                    errors.add(::validify::ValidationError::new_schema(#code) #message);
+                }
+            )
+        })
+        .collect()
+}
+
+/// Output the necessary tokens for field validations when implementing `Validate`. Validations of
+/// fields annotated with `variants` only run when the struct's tag field has one of the values.
+pub fn quote_field_validations(
+    fields: Vec<FieldInfo>,
+    tag: Option<&syn::Ident>,
+    profile: Option<&ProfileSink>,
+) -> Vec<proc_macro2::TokenStream> {
     let mut validations = vec![];
 
     for field_info in fields {
-        let tokens = field_info.quote_validation();
-        validations.extend(tokens);
+        let mut tokens = field_info.quote_validation();
+        if let (Some(profile), false) = (profile, tokens.is_empty()) {
+            let field_name = field_info.name();
+            tokens = vec![match profile {
+                ProfileSink::Tracing => quote!(
+                    ::validify::__profile_field!(#field_name, { #(#tokens)* });
+                ),
+                ProfileSink::Callback(callback) => quote!({
+                    let __start = ::std::time::Instant::now();
+                    #(#tokens)*
+                    #callback(#field_name, __start.elapsed());
+                }),
+            }];
+        }
+        if !field_info.variants.is_empty() {
+            let Some(tag) = tag else {
+                abort!(
+                    field_info.field.span(),
+                    "`variants` requires the struct to specify its tag field, i.e. `#[validate(tag = \"kind\")]`"
+                )
+            };
+            let variants = &field_info.variants;
+            tokens = vec![quote!(
+                if [#(#variants),*].contains(&::std::convert::AsRef::<str>::as_ref(&self.#tag)) {
+                    #(#tokens)*
+                }
+            )];
+        }
+        validations.extend(field_info.wrap_tokens_if_cfg(tokens));
     }
 
     validations
 }
 
 /// Creates a token stream applying the modifiers based on the field annotations.
+///
+/// Returns the modifications, the nested validifies and the modifications that run after validation.
 pub(super) fn quote_field_modifiers(
     fields: Vec<FieldInfo>,
-) -> (Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>) {
+    trace: bool,
+) -> (
+    Vec<proc_macro2::TokenStream>,
+    Vec<proc_macro2::TokenStream>,
+    Vec<proc_macro2::TokenStream>,
+) {
     let mut modifications = vec![];
     let mut nested_validifies = vec![];
+    let mut post_validate_modifications = vec![];
 
     for field_info in fields {
-        let (mods, nested) = field_info.quote_validifes();
-        modifications.extend(mods);
-        nested_validifies.extend(nested);
+        let (mods, nested, post_validate) = field_info.quote_validifes(trace);
+        modifications.extend(field_info.wrap_tokens_if_cfg(mods));
+        nested_validifies.extend(field_info.wrap_tokens_if_cfg(nested));
+        post_validate_modifications.extend(field_info.wrap_tokens_if_cfg(post_validate));
     }
 
-    (modifications, nested_validifies)
+    (
+        modifications,
+        nested_validifies,
+        post_validate_modifications,
+    )
 }
 
 impl Validator {
@@ -121,6 +200,10 @@ impl Validator {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
             }
+            Validator::ParseNumber(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
             Validator::CreditCard(v) => {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
@@ -141,7 +224,41 @@ impl Validator {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
             }
+            Validator::Finite(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
+            Validator::TryInto(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
+            Validator::ValidRange(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
+            Validator::RangeLen(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
             Validator::Length(v) => {
+                if !v.none_is_zero {
+                    let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                    return ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens));
+                }
+                if !field_info.is_option() {
+                    abort!(
+                        field_info.field.span(),
+                        "`none_is_zero` can only be used on `Option` fields"
+                    )
+                }
+                let tokens = v.to_validify_tokens(field_name.clone(), validator_param, false);
+                // `None` is checked as if it were an empty value
+                let none_tokens = v.to_validify_tokens(field_name, quote!(""), false);
+                ValidationTokens::Normal(
+                    field_info.wrap_tokens_if_option_or_else(tokens, none_tokens),
+                )
+            }
+            Validator::OneOf(v) => {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
             }
@@ -153,10 +270,18 @@ impl Validator {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
             }
+            Validator::EachLine(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
             Validator::Contains(v) => {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
             }
+            Validator::ContainsWord(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
             Validator::MustMatch(v) => {
                 let ident = field_info.field.ident.as_ref();
                 let validator_param = quote!(&self.#ident);
@@ -169,6 +294,21 @@ impl Validator {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(tokens)
             }
+            Validator::Requires(v) => {
+                if !field_info.is_option() {
+                    abort!(
+                        field_info.field.span(),
+                        "`requires` can only be used on `Option` fields"
+                    )
+                }
+                let ident = field_info.field.ident.as_ref();
+                let tokens = v.to_validify_tokens(field_name);
+                ValidationTokens::Normal(quote!(
+                    if self.#ident.is_some() {
+                        #tokens
+                    }
+                ))
+            }
             Validator::In(v) => {
                 let ident = field_info.field.ident.as_ref();
                 let validator_param = quote!(&self.#ident);
@@ -184,72 +324,81 @@ impl Validator {
                 let tokens = v.to_validify_tokens(field_name, validator_param, false);
                 ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
             }
-            Validator::Iter(v) => {
-                let validator_param = quote!(el);
-                let inner_tokens = v.iter().map(|v| match v {
-                    Validator::Iter(_) => {
-                        abort!(field_info.field.span(), "`iter` validator cannot be nested")
-                    }
-                    Validator::Nested => {
-                        abort!(field_info.field.span(), "`nested` is not valid in `iter`. To recursively validate collections, use `nested` directly on the field")
-                    },
-                    Validator::Email(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Url(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::CreditCard(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Phone(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Custom(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Range(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Length(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Ip(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::NonControlCharacter(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Required(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::MustMatch(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Regex(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Contains(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    }
-                    Validator::Time(v) => {
-                        v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
-                    },
-                    Validator::In(v) => v.to_validify_tokens(field_name.clone(), validator_param.clone(), false, true),
-                });
+            Validator::Future(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
+            Validator::Past(v) => {
+                let tokens = v.to_validify_tokens(field_name, validator_param, false);
+                ValidationTokens::Normal(field_info.wrap_tokens_if_option(tokens))
+            }
+            // Warnings are collected separately by `validate_warnings`
+            Validator::Warn(_) => ValidationTokens::Normal(quote!()),
+            Validator::Iter(v) | Validator::Values(v) | Validator::Each(v) => {
+                let inner_tokens = v
+                    .iter()
+                    .map(|v| v.to_collection_tokens(field_info, &field_name));
                 let ident = field_info.field.ident.as_ref();
+                // Tuples cannot be iterated so the validators are repeated for each element
+                if let Validator::Each(_) = self {
+                    let inner_tokens = inner_tokens.collect::<Vec<_>>();
+                    let elements = field_info.homogeneous_tuple_len().map(|i| {
+                        let index = syn::Index::from(i);
+                        quote!({
+                            let __i = #i;
+                            let el = &self.#ident.#index;
+                            #(#inner_tokens)*
+                        })
+                    });
+                    return ValidationTokens::Normal(quote!(#(#elements)*));
+                }
+                // Map values are located by their keys instead of their index
+                let iter = if let Validator::Values(_) = self {
+                    quote!(self.#ident.iter())
+                } else {
+                    quote!(self.#ident.iter().enumerate())
+                };
                 let tokens = quote!(
-                    for (__i, el) in self.#ident.iter().enumerate() {
+                    for (__i, el) in #iter {
                         #(#inner_tokens)*
                     }
                 );
                 ValidationTokens::Normal(tokens)
             }
+            Validator::EachEntry { key, value } => {
+                // Each side gets its own scope so the pair's index can be suffixed with its part
+                let parts = [
+                    ("key", key, quote!(__key)),
+                    ("value", value, quote!(__value)),
+                ]
+                .into_iter()
+                .filter(|(_, validators, _)| !validators.is_empty())
+                .map(|(part, validators, el)| {
+                    let inner_tokens = validators
+                        .iter()
+                        .map(|v| v.to_collection_tokens(field_info, &field_name));
+                    quote!({
+                        let __i = &::std::format!("{}/{}", __idx, #part);
+                        let el = #el;
+                        #(#inner_tokens)*
+                    })
+                });
+                let ident = field_info.field.ident.as_ref();
+                ValidationTokens::Normal(quote!(
+                    #[allow(unused_variables)]
+                    for (__idx, (__key, __value)) in self.#ident.iter().enumerate() {
+                        #(#parts)*
+                    }
+                ))
+            }
+            Validator::Nested if field_info.warnings => ValidationTokens::Nested(
+                field_info.wrap_tokens_if_option(field_info.quote_nested_warnings()),
+            ),
             Validator::Nested => {
                 let validator_field = field_info.quote_validator_field();
                 let field_name = field_info.name();
                 let quoted = quote!(
-                    if let Err(mut errs) = #validator_field.validate() {
+                    if let Err(mut errs) = #validator_field.validate_groups(__groups) {
                         errs.errors_mut().iter_mut().for_each(|err| err.set_location(#field_name));
                         errors.merge(errs);
                     }
@@ -308,56 +457,65 @@ impl Length {
             ref min,
             ref max,
             ref equal,
+            ref min_from,
+            ref max_from,
+            ref equal_from,
             ..
         } = self;
 
         let quoted_error = self.quote_error(&field_name);
-        let error_param = quote!(err.add_param("actual", &#validator_param.len()););
-        let error_location = if in_iter {
-            quote!(err.set_location_idx(__i, #field_name);)
-        } else {
-            quote!(err.set_location(#field_name);)
-        };
 
-        let min_err_param_quoted = if let Some(ref v) = min {
-            quote!(err.add_param("min", &#v);)
+        // Whitespace is filtered from the chars of the string before counting
+        let (validate_fn, value, error_param) = if self.ignore_whitespace {
+            let value = quote!(::std::convert::AsRef::<str>::as_ref(#validator_param));
+            (
+                quote!(::validify::validate_non_whitespace_length),
+                value.clone(),
+                quote!(err.add_param("actual", &#value.chars().filter(|c| !c.is_whitespace()).count());),
+            )
         } else {
-            quote!()
+            // Resolves to the value's `HasLen` impl, or to its chars if it is any other string type
+            let value = quote!({
+                use ::validify::__private::{SelectHasLen as _, SelectStrLen as _};
+                (&::validify::__private::Select(#validator_param)).has_len()
+            });
+            (
+                quote!(::validify::validate_length),
+                value.clone(),
+                quote!(err.add_param("actual", &::validify::traits::HasLen::length(&#value));),
+            )
         };
-
-        let max_err_param_quoted = if let Some(ref v) = max {
-            quote!(err.add_param("max", &#v);)
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
         } else {
-            quote!()
+            quote!(err.set_location(#field_name);)
         };
 
-        let equal_err_param_quoted = if let Some(ref v) = equal {
-            quote!(err.add_param("equal", &#v);)
-        } else {
-            quote!()
+        // Bounds are bound to locals so lazily initialized values are only dereferenced once
+        let bound = |value: &Option<ValueOrPath<u64>>, from: &Option<syn::Path>, name: &str| {
+            let ident = syn::Ident::new(&format!("__length_{name}"), Span::call_site());
+            let bound = match (value, from) {
+                (Some(value), _) => value.tokens(),
+                (None, Some(path)) => quote!(*#path),
+                (None, None) => return (quote!(), quote!(None), quote!()),
+            };
+            (
+                quote!(let #ident = #bound as u64;),
+                quote!(Some(#ident)),
+                quote!(err.add_param(#name, &#ident);),
+            )
         };
 
-        let min_tokens = min
-            .as_ref()
-            .map(ValueOrPath::tokens)
-            .map(|x| quote!(Some(#x as u64)))
-            .unwrap_or(quote!(None));
+        let (min_local, min_tokens, min_err_param_quoted) = bound(min, min_from, "min");
+        let (max_local, max_tokens, max_err_param_quoted) = bound(max, max_from, "max");
+        let (equal_local, equal_tokens, equal_err_param_quoted) = bound(equal, equal_from, "equal");
 
-        let max_tokens = max
-            .as_ref()
-            .map(ValueOrPath::tokens)
-            .map(|x| quote!(Some(#x as u64)))
-            .unwrap_or(quote!(None));
-
-        let equal_tokens = equal
-            .as_ref()
-            .map(ValueOrPath::tokens)
-            .map(|x| quote!(Some(#x as u64)))
-            .unwrap_or(quote!(None));
-
-        quote!(
-            if !::validify::validate_length(
-                #validator_param,
+        quote!({
+            #min_local
+            #max_local
+            #equal_local
+            if !#validate_fn(
+                #value,
                 #min_tokens,
                 #max_tokens,
                 #equal_tokens
@@ -370,7 +528,7 @@ impl Length {
                 #error_location
                 errors.add(err);
             }
-        )
+        })
     }
 }
 
@@ -389,9 +547,18 @@ impl Range {
         };
 
         let Range {
-            ref min, ref max, ..
+            ref min,
+            ref max,
+            ref step,
+            ref min_message,
+            ref max_message,
+            ..
         } = self;
 
+        if self.is_char() {
+            return self.quote_char_range(validator_param, quoted_error, error_location);
+        }
+
         let min_err_param_quoted = if let Some(v) = min {
             quote!(err.add_param("min", &#v);)
         } else {
@@ -406,25 +573,61 @@ impl Range {
 
         let min_tokens = min
             .as_ref()
-            .map(ValueOrPath::tokens)
             .map(|x| quote!(Some(#x as f64)))
             .unwrap_or(quote!(None));
 
         let max_tokens = max
             .as_ref()
-            .map(ValueOrPath::tokens)
             .map(|x| quote!(Some(#x as f64)))
             .unwrap_or(quote!(None));
 
+        // Steps are counted from the minimum, or from 0 if there is none
+        let (step_quoted, step_err_param_quoted) = match step {
+            Some(step) => {
+                let base = min
+                    .as_ref()
+                    .map(|x| quote!(#x as f64))
+                    .unwrap_or(quote!(0.));
+                (
+                    quote!(|| !::validify::validate_step(*#validator_param as f64, #base, #step as f64)),
+                    quote!(err.add_param("step", &#step);),
+                )
+            }
+            None => (quote!(), quote!()),
+        };
+
+        // Per bound messages take precedence over the general one for the bound that failed
+        let min_message_quoted = match (min, min_message) {
+            (Some(v), Some(m)) => quote!(
+                if (*#validator_param as f64) < (#v as f64) {
+                    err.set_message(String::from(#m));
+                }
+            ),
+            _ => quote!(),
+        };
+
+        let max_message_quoted = match (max, max_message) {
+            (Some(v), Some(m)) => quote!(
+                if (*#validator_param as f64) > (#v as f64) {
+                    err.set_message(String::from(#m));
+                }
+            ),
+            _ => quote!(),
+        };
+
+        // NaN compares false to everything, so it and infinities are rejected explicitly
         quote!(
-            if !::validify::validate_range(
+            if !(*#validator_param as f64).is_finite() || !::validify::validate_range(
                 *#validator_param as f64,
                 #min_tokens,
                 #max_tokens
-            ) {
+            ) #step_quoted {
                 #quoted_error
+                #min_message_quoted
+                #max_message_quoted
                 #min_err_param_quoted
                 #max_err_param_quoted
+                #step_err_param_quoted
                 err.add_param("actual", &#validator_param);
                 #error_location
                 errors.add(err);
@@ -433,6 +636,167 @@ impl Range {
     }
 }
 
+impl Range {
+    /// Chars are compared directly since they cannot be cast to floats.
+    fn quote_char_range(
+        &self,
+        validator_param: TokenStream,
+        quoted_error: TokenStream,
+        error_location: TokenStream,
+    ) -> TokenStream {
+        let mut params = vec![];
+        let mut messages = vec![];
+
+        let bounds = [
+            ("min", &self.min, &self.min_message, quote!(<)),
+            ("max", &self.max, &self.max_message, quote!(>)),
+        ];
+
+        for (key, bound, message, op) in bounds {
+            let Some(bound) = bound else {
+                continue;
+            };
+            params.push(quote!(err.add_param(#key, &#bound);));
+            if let Some(message) = message {
+                messages.push(quote!(
+                    if *#validator_param #op #bound {
+                        err.set_message(String::from(#message));
+                    }
+                ));
+            }
+        }
+
+        let min = self
+            .min
+            .as_ref()
+            .map(|x| quote!(Some(#x)))
+            .unwrap_or(quote!(None));
+        let max = self
+            .max
+            .as_ref()
+            .map(|x| quote!(Some(#x)))
+            .unwrap_or(quote!(None));
+
+        quote!(
+            if !::validify::validate_range(*#validator_param, #min, #max) {
+                #quoted_error
+                #(#messages)*
+                #(#params)*
+                err.add_param("actual", &#validator_param);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
+impl Finite {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        quote!(
+            if !(*#validator_param as f64).is_finite() {
+                #quoted_error
+                err.add_param("actual", &#validator_param);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
+impl TryConvert {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let ty = &self.ty;
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        quote!(
+            if <#ty as ::std::convert::TryFrom<_>>::try_from(::std::clone::Clone::clone(#validator_param)).is_err() {
+                #quoted_error
+                err.add_param("actual", &#validator_param);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
+impl ValidRange {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        quote!(
+            if !::validify::validate_valid_range(#validator_param) {
+                #quoted_error
+                err.add_param("actual", &#validator_param);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
+impl RangeLen {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        let (min, min_err_param) = match self.min {
+            Some(min) => (quote!(Some(#min)), quote!(err.add_param("min", &#min);)),
+            None => (quote!(None), quote!()),
+        };
+        let (max, max_err_param) = match self.max {
+            Some(max) => (quote!(Some(#max)), quote!(err.add_param("max", &#max);)),
+            None => (quote!(None), quote!()),
+        };
+        quote!(
+            if !::validify::validate_range_len(#validator_param, #min, #max) {
+                #quoted_error
+                #min_err_param
+                #max_err_param
+                err.add_param("actual", &::validify::traits::Span::span_len(#validator_param));
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
 impl CreditCard {
     fn to_validify_tokens(
         &self,
@@ -488,7 +852,17 @@ impl Custom {
         validator_param: TokenStream,
         in_iter: bool,
     ) -> TokenStream {
-        let Custom { ref path, .. } = self;
+        let Custom {
+            ref path,
+            timeout_ms,
+            ref groups,
+            by_value,
+            ref code,
+            ..
+        } = self;
+
+        // Overridden codes replace the code of the returned error
+        let set_code = code.as_ref().map(|code| quote!(err.set_code(#code);));
 
         let err_with_msg = if let Some(msg) = self.message() {
             quote!(err.with_message(#msg.to_string()))
@@ -501,8 +875,31 @@ impl Custom {
             quote!(err.set_location(#field_name);)
         };
 
-        quote!(
-            if let Err(mut err) = #path(#validator_param) {
+        // Functions can return any error convertible into a `ValidationError`
+        let into_error = quote!(::std::convert::Into::<::validify::ValidationError>::into);
+
+        // With a timeout, the value is cloned and moved to the validation thread
+        let call = match (timeout_ms, by_value) {
+            (Some(timeout_ms), false) => quote!(::validify::__validate_with_timeout!(
+                ::std::clone::Clone::clone(#validator_param),
+                #timeout_ms,
+                |__value| #path(&__value).map_err(#into_error)
+            )),
+            (Some(timeout_ms), true) => quote!(::validify::__validate_with_timeout!(
+                ::std::clone::Clone::clone(#validator_param),
+                #timeout_ms,
+                |__value| #path(__value).map_err(#into_error)
+            )),
+            (None, false) => quote!(#path(#validator_param).map_err(#into_error)),
+            // Functions consuming the value get a clone of the field
+            (None, true) => {
+                quote!(#path(::std::clone::Clone::clone(#validator_param)).map_err(#into_error))
+            }
+        };
+
+        let tokens = quote!(
+            if let Err(mut err) = #call {
+                #set_code
                 let f_name = err.field_name().map(|s|s.to_string());
                 if let Some(field_name) = f_name {
                     err.set_location(field_name);
@@ -512,6 +909,17 @@ impl Custom {
                 }
                 errors.add(#err_with_msg);
             };
+        );
+
+        // Validations without groups always run
+        if groups.is_empty() {
+            return tokens;
+        }
+
+        quote!(
+            if [#(#groups),*].iter().any(|group| __groups.contains(group)) {
+                #tokens
+            }
         )
     }
 }
@@ -564,6 +972,139 @@ impl Url {
     }
 }
 
+impl ParseNumber {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let decimal_separator = self.decimal_separator;
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        quote!(
+            if !::validify::validate_parse_number(#validator_param, #decimal_separator) {
+                #quoted_error
+                err.add_param("actual", &#validator_param);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
+impl Validator {
+    /// Tokens for a validator applied to each element of a collection, bound to `el` and located
+    /// by `__i`.
+    fn to_collection_tokens(
+        &self,
+        field_info: &crate::fields::FieldInfo,
+        field_name: &str,
+    ) -> TokenStream {
+        let field_name = field_name.to_string();
+        let validator_param = quote!(el);
+        match self {
+            Validator::Iter(_)
+            | Validator::Values(_)
+            | Validator::Each(_)
+            | Validator::EachEntry { .. } => {
+                abort!(
+                    field_info.field.span(),
+                    "`iter`, `values`, `each` and `each_entry` validators cannot be nested"
+                )
+            }
+            Validator::Warn(_) => {
+                abort!(field_info.field.span(), "`warn` is not valid in `iter`")
+            }
+            Validator::Nested => {
+                abort!(field_info.field.span(), "`nested` is not valid in `iter`. To recursively validate collections, use `nested` directly on the field")
+            }
+            Validator::Requires(_) => {
+                abort!(field_info.field.span(), "`requires` is not valid in `iter`")
+            }
+            Validator::Finite(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::TryInto(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::ValidRange(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::RangeLen(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Email(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Url(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::ParseNumber(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::CreditCard(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Phone(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Custom(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Range(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Length(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::OneOf(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Ip(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::NonControlCharacter(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Required(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::MustMatch(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Regex(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::EachLine(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Contains(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::ContainsWord(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Time(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Future(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::Past(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), true)
+            }
+            Validator::In(v) => {
+                v.to_validify_tokens(field_name.clone(), validator_param.clone(), false, true)
+            }
+        }
+    }
+}
+
 impl Email {
     fn to_validify_tokens(
         &self,
@@ -577,10 +1118,25 @@ impl Email {
         } else {
             quote!(err.set_location(#field_name);)
         };
+        if !self.require_tld {
+            return quote!(
+                if !::validify::validate_email(#validator_param) {
+                    #quoted_error
+                    err.add_param("actual", &#validator_param);
+                    #error_location
+                    errors.add(err);
+                }
+            );
+        }
         quote!(
-            if !::validify::validate_email(#validator_param) {
+            if !::validify::validate_email(#validator_param)
+                || !::validify::validate_email_tld(#validator_param)
+            {
                 #quoted_error
                 err.add_param("actual", &#validator_param);
+                if let Some((_, domain)) = ::std::convert::AsRef::<str>::as_ref(#validator_param).rsplit_once('@') {
+                    err.add_param("domain", &domain);
+                }
                 #error_location
                 errors.add(err);
             }
@@ -621,16 +1177,16 @@ impl Regex {
         validator_param: TokenStream,
         in_iter: bool,
     ) -> TokenStream {
-        let Regex { ref path, .. } = self;
         let quoted_error = self.quote_error(&field_name);
         let error_location = if in_iter {
             quote!(err.set_location_idx(__i, #field_name);)
         } else {
             quote!(err.set_location(#field_name);)
         };
+        let is_match = self.quote_is_match(&validator_param);
 
         quote!(
-            if !#path.is_match(#validator_param) {
+            if !(#is_match) {
                 #quoted_error
                 err.add_param("actual", &#validator_param);
                 #error_location
@@ -638,6 +1194,81 @@ impl Regex {
             }
         )
     }
+
+    fn quote_is_match(&self, validator_param: &TokenStream) -> TokenStream {
+        if !self.transforms() {
+            return self.quote_is_match_raw(validator_param);
+        }
+
+        // The transforms are applied to a copy, the field itself is left as it is
+        let mut transformed = quote!(::std::convert::AsRef::<str>::as_ref(#validator_param));
+        if self.trim_first {
+            transformed = quote!(#transformed.trim());
+        }
+        if self.lowercase_first {
+            transformed = quote!(#transformed.to_lowercase());
+        }
+        let is_match = self.quote_is_match_raw(&quote!(__transformed));
+
+        quote!({
+            let __transformed: &str = &#transformed;
+            #is_match
+        })
+    }
+
+    fn quote_is_match_raw(&self, validator_param: &TokenStream) -> TokenStream {
+        let Regex { ref regex, .. } = self;
+
+        // Anchored literals are checked directly, anything else gets compiled once on first use
+        match (regex, self.literal()) {
+            (ValueOrPath::Path(path), _) => quote!(#path.is_match(#validator_param)),
+            (ValueOrPath::Value(_), Some(RegexLiteral::Exact(literal))) => {
+                quote!(::std::convert::AsRef::<str>::as_ref(#validator_param) == #literal)
+            }
+            (ValueOrPath::Value(_), Some(RegexLiteral::Prefix(literal))) => {
+                quote!(::std::convert::AsRef::<str>::as_ref(#validator_param).starts_with(#literal))
+            }
+            (ValueOrPath::Value(pattern), None) => quote!({
+                static RE: ::std::sync::OnceLock<::validify::__private::Regex> =
+                    ::std::sync::OnceLock::new();
+                ::validify::__private::lazy_regex(&RE, #pattern)
+            }
+            .is_match(#validator_param)),
+        }
+    }
+}
+
+impl EachLine {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        let is_match = self
+            .regex
+            .as_ref()
+            .expect("each_line always has a regex")
+            .quote_is_match(&quote!(line));
+
+        quote!(
+            for (__line, line) in ::std::convert::AsRef::<str>::as_ref(#validator_param).lines().enumerate() {
+                if !(#is_match) {
+                    #quoted_error
+                    err.add_param("line", &(__line + 1));
+                    err.add_param("actual", &line);
+                    #error_location
+                    errors.add(err);
+                }
+            }
+        )
+    }
 }
 
 impl In {
@@ -677,6 +1308,66 @@ impl In {
     }
 }
 
+impl OneOf {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let OneOf {
+            ref values,
+            ref enum_discriminants,
+            case_insensitive,
+            ..
+        } = self;
+
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+
+        // Values that do not fit in the discriminant type cannot be any of the discriminants
+        if let Some(enum_path) = enum_discriminants {
+            return quote!(
+                if !::std::convert::TryInto::<i128>::try_into(*#validator_param).is_ok_and(|__value| {
+                    <#enum_path as ::validify::Discriminants>::DISCRIMINANTS.contains(&__value)
+                }) {
+                    #quoted_error
+                    err.add_param("actual", &#validator_param);
+                    err.add_param("allowed", &<#enum_path as ::validify::Discriminants>::DISCRIMINANTS);
+                    #error_location
+                    errors.add(err);
+                }
+            );
+        }
+
+        // The list is lowercased here so it is not lowercased on every call. The params contain
+        // the values as they were compared.
+        let (actual, allowed) = if *case_insensitive {
+            let allowed = values.iter().map(|v| v.to_lowercase());
+            (
+                quote!(::std::convert::AsRef::<str>::as_ref(#validator_param).to_lowercase()),
+                quote!([#(#allowed),*]),
+            )
+        } else {
+            (quote!(#validator_param), quote!([#(#values),*]))
+        };
+
+        quote!(
+            if !::validify::validate_one_of(#validator_param, &#allowed, #case_insensitive) {
+                #quoted_error
+                err.add_param("actual", &#actual);
+                err.add_param("allowed", &#allowed);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
 impl Contains {
     fn to_validify_tokens(
         &self,
@@ -703,13 +1394,45 @@ impl Contains {
         let added_param = matches!(value, Some(ValueOrPath::Value(_)))
             .then_some(quote!(err.add_param("target", &#value);));
 
-        quote!(
-            if !::validify::validate_contains(#validator_param, &#validation_val, #not) {
+        quote!({
+            use ::validify::__private::{SelectContains as _, SelectStrContains as _};
+            let __haystack = (&::validify::__private::Select(#validator_param)).haystack();
+            if !::validify::validate_contains(__haystack, &#validation_val, #not) {
                 #quoted_error
                 #added_param
                 #error_location
                 errors.add(err);
             }
+        })
+    }
+}
+
+impl ContainsWord {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let ContainsWord { ref value, .. } = self;
+
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+
+        quote!(
+            if !::validify::validate_contains_word(
+                #validator_param,
+                ::std::convert::AsRef::<str>::as_ref(&#value)
+            ) {
+                #quoted_error
+                err.add_param("target", &#value);
+                #error_location
+                errors.add(err);
+            }
         )
     }
 }
@@ -737,6 +1460,80 @@ impl Required {
     }
 }
 
+impl Requires {
+    /// Adds an error located at each of the dependents that is missing.
+    fn to_validify_tokens(&self, field_name: String) -> TokenStream {
+        let checks = self.dependents.iter().map(|(dependent, dependent_name)| {
+            let quoted_error = self.quote_error(dependent_name);
+            quote!(
+                if self.#dependent.is_none() {
+                    #quoted_error
+                    err.add_param("required_by", &#field_name);
+                    err.set_location(#dependent_name);
+                    errors.add(err);
+                }
+            )
+        });
+        quote!(#(#checks)*)
+    }
+}
+
+impl Future {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        let grace = self.grace;
+
+        quote!(
+            if let Err(target) = ::validify::time::future(#validator_param, #grace) {
+                #quoted_error
+                err.add_param("actual", &#validator_param.to_rfc3339());
+                err.add_param("target", &target.to_rfc3339());
+                err.add_param("grace", &#grace);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
+impl Past {
+    fn to_validify_tokens(
+        &self,
+        field_name: String,
+        validator_param: TokenStream,
+        in_iter: bool,
+    ) -> TokenStream {
+        let quoted_error = self.quote_error(&field_name);
+        let error_location = if in_iter {
+            quote!(err.set_location_idx(__i, #field_name);)
+        } else {
+            quote!(err.set_location(#field_name);)
+        };
+        let grace = self.grace;
+
+        quote!(
+            if let Err(target) = ::validify::time::past(#validator_param, #grace) {
+                #quoted_error
+                err.add_param("actual", &#validator_param.to_rfc3339());
+                err.add_param("target", &target.to_rfc3339());
+                err.add_param("grace", &#grace);
+                #error_location
+                errors.add(err);
+            }
+        )
+    }
+}
+
 impl Time {
     fn to_validify_tokens(
         &self,
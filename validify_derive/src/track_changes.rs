@@ -0,0 +1,71 @@
+use crate::fields::FieldInfo;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generates `validate_into_tracked`, which validifies the struct and reports the fields whose
+/// values were changed by modifiers. Only fields with modifiers are compared, unless the struct
+/// has struct level modifiers which may change any of them.
+pub fn quote_track_changes(
+    input: &syn::DeriveInput,
+    fields: &[FieldInfo],
+    all_fields: bool,
+) -> TokenStream {
+    let ident = &input.ident;
+
+    let tracked: Vec<&FieldInfo> = fields
+        .iter()
+        .filter(|field| all_fields || !field.modifiers.is_empty())
+        .collect();
+
+    // Comparing requires a snapshot of the field, so it must be `Clone` as well
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for field in tracked.iter() {
+        let ty = &field.field.ty;
+        where_clause.predicates.push(syn::parse_quote!(
+            #ty: ::std::clone::Clone + ::std::cmp::PartialEq
+        ));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut snapshots = vec![];
+    let mut comparisons = vec![];
+
+    for field in tracked {
+        let field_ident = field.field.ident.as_ref().unwrap();
+        let before = format_ident!("__before_{field_ident}");
+        let cfg_attrs = field.cfg_attrs();
+        let field_name = field.name();
+
+        snapshots.push(quote!(
+            #(#cfg_attrs)*
+            let #before = ::std::clone::Clone::clone(&self.#field_ident);
+        ));
+        comparisons.push(quote!(
+            #(#cfg_attrs)*
+            if self.#field_ident != #before {
+                changes.push(::std::string::String::from(#field_name));
+            }
+        ));
+    }
+
+    quote!(
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Validifies the struct and returns it along with the names of the fields whose values
+            /// were changed by modifiers.
+            pub fn validate_into_tracked(
+                mut self,
+            ) -> ::std::result::Result<(Self, ::std::vec::Vec<::std::string::String>), ::validify::ValidationErrors> {
+                #(#snapshots)*
+
+                ::validify::Validify::validify(&mut self)?;
+
+                let mut changes = ::std::vec::Vec::new();
+
+                #(#comparisons)*
+
+                ::std::result::Result::Ok((self, changes))
+            }
+        }
+    )
+}
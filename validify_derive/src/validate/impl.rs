@@ -1,12 +1,16 @@
 use super::parser::*;
 use super::validation::{
-    Contains, CreditCard, Custom, Email, In, Ip, MustMatch, NonControlChar, Phone, Regex, Required,
-    SchemaValidation, Url, Validator,
+    Contains, ContainsWord, CreditCard, Custom, Email, Finite, Future, In, Ip, MustMatch,
+    NonControlChar, ParseNumber, Past, Phone, PredicateValidation, ProfileSink, Required,
+    SchemaValidation, Url, ValidRange, Validator,
 };
 use crate::fields::FieldInfo;
+use crate::json_schema::quote_json_schema;
+use crate::rules::quote_rules;
 use crate::tokens::quote_field_validations;
-use crate::tokens::quote_schema_validations;
+use crate::tokens::{quote_predicate_validations, quote_schema_validations};
 use crate::validate::ValidationMeta;
+use crate::validify::modifier::Modifier;
 use proc_macro_error::abort;
 use quote::quote;
 use syn::meta::ParseNestedMeta;
@@ -20,63 +24,447 @@ const EMAIL: &str = "email";
 const URL: &str = "url";
 const LENGTH: &str = "length";
 const RANGE: &str = "range";
+const FINITE: &str = "finite";
+const TRY_INTO: &str = "try_into";
+const VALID_RANGE: &str = "valid_range";
+const RANGE_LEN: &str = "range_len";
 const MUST_MATCH: &str = "must_match";
 const CONTAINS: &str = "contains";
 const CONTAINS_NOT: &str = "contains_not";
+const CONTAINS_WORD: &str = "contains_word";
 const NON_CONTROL_CHAR: &str = "non_control_char";
 const CUSTOM: &str = "custom";
 const REGEX: &str = "regex";
+const EACH_LINE: &str = "each_line";
 const CREDIT_CARD: &str = "credit_card";
 const PHONE: &str = "phone";
 const REQUIRED: &str = "required";
+const REQUIRES: &str = "requires";
 const IS_IN: &str = "is_in";
 const NOT_IN: &str = "not_in";
+const ONE_OF: &str = "one_of";
 const IP: &str = "ip";
 const TIME: &str = "time";
+const FUTURE: &str = "future";
+const PAST: &str = "past";
 const ITER: &str = "iter";
+const VALUES: &str = "values";
+const EACH: &str = "each";
+const EACH_ENTRY: &str = "each_entry";
+const NESTED: &str = "nested";
+const WARN: &str = "warn";
+const WAS_DEFAULTED_FIELD: &str = "was_defaulted_field";
+const VARIANTS: &str = "variants";
+const TAG: &str = "tag";
+const CODE_ENUM: &str = "code_enum";
+const RULES: &str = "rules";
+const JSON_SCHEMA: &str = "json_schema";
+const DEDUP_ERRORS: &str = "dedup_errors";
+const FLAT_ERRORS: &str = "flat_errors";
+const CODE_PREFIX: &str = "code_prefix";
+const PREDICATE: &str = "predicate";
+const APPLY: &str = "apply";
+const PROFILE: &str = "profile";
+const PARTIAL: &str = "partial";
+const MAX_ERRORS: &str = "max_errors";
 
 pub fn impl_validate(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let ident = &input.ident;
 
-    let field_info = FieldInfo::collect(input);
-    let validations = quote_field_validations(field_info);
+    let mut field_info = FieldInfo::collect(input);
+
+    let StructValidations {
+        schema,
+        predicates,
+        code_enum,
+        rules,
+        json_schema,
+        dedup_errors,
+        flat_errors,
+        code_prefix,
+        tag,
+        profile,
+        partial,
+        max_errors,
+    } = collect_struct_validation(&input.attrs).unwrap_or_else(|e| abort!(e.span(), e));
+
+    if partial {
+        skip_none_checks(&mut field_info);
+    }
+
+    check_parsed_numbers(&mut field_info);
+
+    resolve_requires(&mut field_info);
+
+    if let Some(prefix) = code_prefix {
+        for validator in field_info
+            .iter_mut()
+            .flat_map(|field| field.validations.iter_mut())
+        {
+            validator.prefix_code(&prefix);
+        }
+    }
+
+    let schema_validations = quote_schema_validations(&schema);
+
+    let predicate_validations = quote_predicate_validations(&predicates);
+
+    let code_enum = code_enum.map(|code_enum| quote_code_enum(input, &code_enum, &field_info));
+
+    let rules = rules.then(|| quote_rules(input, &field_info));
+
+    let json_schema = json_schema.then(|| quote_json_schema(input, &field_info));
+
+    if let Some(ref tag) = tag {
+        if !field_info
+            .iter()
+            .any(|field| field.field.ident.as_ref() == Some(tag))
+        {
+            abort!(
+                tag.span(),
+                format!("Tag field `{tag}` does not exist on the struct")
+            )
+        }
+    }
+
+    let warnings = quote_warnings(&mut field_info, tag.as_ref());
 
-    let struct_validations = collect_struct_validation(&input.attrs).unwrap();
-    let schema_validations = quote_schema_validations(&struct_validations);
+    let validations = quote_field_validations(field_info, tag.as_ref(), profile.as_ref());
+
+    let dedup_errors = dedup_errors.then(|| quote!(errors.dedup();));
+
+    let new_errors = match max_errors {
+        Some(max) => quote!(::validify::ValidationErrors::with_max_errors(#max)),
+        None => quote!(::validify::ValidationErrors::new()),
+    };
 
     let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
+    let flat_errors = flat_errors.then(|| {
+        quote!(
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Validates the struct and returns its errors keyed by the dotted paths of their
+                /// locations. An empty vec means the struct is valid.
+                pub fn validate_flat(&self) -> ::std::vec::Vec<(::std::string::String, ::validify::ValidationError)> {
+                    match <Self as ::validify::Validate>::validate(self) {
+                        ::std::result::Result::Ok(()) => ::std::vec::Vec::new(),
+                        ::std::result::Result::Err(errors) => errors.flatten(),
+                    }
+                }
+            }
+        )
+    });
+
     quote!(
         impl #impl_generics ::validify::Validate for #ident #ty_generics #where_clause {
             fn validate(&self) -> ::std::result::Result<(), ::validify::ValidationErrors> {
-                let mut errors = ::validify::ValidationErrors::new();
+                <Self as ::validify::Validate>::validate_groups(self, &[])
+            }
+
+            fn validate_groups(&self, __groups: &[&str]) -> ::std::result::Result<(), ::validify::ValidationErrors> {
+                let mut errors = #new_errors;
 
                 #(#validations)*
 
                 #(#schema_validations)*
 
+                #(#predicate_validations)*
+
+                #dedup_errors
+
                 if errors.is_empty() {
                     ::std::result::Result::Ok(())
                 } else {
                     ::std::result::Result::Err(errors)
                 }
             }
+
+            #warnings
         }
+
+        #code_enum
+
+        #rules
+
+        #json_schema
+
+        #flat_errors
     )
 }
 
-/// Find if a struct has some schema validation and returns the info if so
-fn collect_struct_validation(
-    attrs: &[syn::Attribute],
-) -> Result<Vec<SchemaValidation>, syn::Error> {
-    let mut validations = vec![];
+/// Generates `validate_warnings` running the validators declared in `warn`, if any, along with the
+/// warnings of nested structs. The validators are moved out of the fields into copies of them so
+/// their tokens are generated as usual.
+fn quote_warnings(
+    fields: &mut [FieldInfo],
+    tag: Option<&syn::Ident>,
+) -> Option<proc_macro2::TokenStream> {
+    let warning_fields: Vec<FieldInfo> = fields
+        .iter_mut()
+        .filter_map(|field| {
+            let validations: Vec<Validator> = field
+                .validations
+                .iter_mut()
+                .filter_map(|validator| match validator {
+                    Validator::Warn(validators) => Some(std::mem::take(validators)),
+                    Validator::Nested => Some(vec![Validator::Nested]),
+                    _ => None,
+                })
+                .flatten()
+                .collect();
+            if validations.is_empty() {
+                return None;
+            }
+            Some(FieldInfo {
+                variants: field.variants.clone(),
+                warnings: true,
+                ..FieldInfo::new(
+                    field.field.clone(),
+                    field.name.clone(),
+                    field.original_name.clone(),
+                    validations,
+                    vec![],
+                    field.rename_rule,
+                    field.was_defaulted.clone(),
+                )
+            })
+        })
+        .collect();
+
+    if warning_fields.is_empty() {
+        return None;
+    }
+
+    let warnings = quote_field_validations(warning_fields, tag, None);
+
+    Some(quote!(
+        fn validate_warnings(&self) -> ::validify::ValidationErrors {
+            // Warnings are collected with the same tokens as errors
+            let __groups: &[&str] = &[];
+            let mut errors = ::validify::ValidationErrors::new();
+            #(#warnings)*
+            errors
+        }
+    ))
+}
+
+/// Removes the validators of optional fields that fail on `None`, so that only the values of the
+/// fields that are present are validated. `length(none_is_zero)` is kept without the flag.
+fn skip_none_checks(fields: &mut [FieldInfo]) {
+    fn skip(validators: &mut Vec<Validator>) {
+        validators.retain(|validator| !matches!(validator, Validator::Required(_)));
+        for validator in validators.iter_mut() {
+            match validator {
+                Validator::Length(length) => length.none_is_zero = false,
+                Validator::Warn(validators) => skip(validators),
+                _ => {}
+            }
+        }
+    }
+
+    for field in fields.iter_mut().filter(|field| field.is_option()) {
+        skip(&mut field.validations);
+    }
+}
+
+/// Adds a `parse_number` check in front of the validators of fields with the `parse_number`
+/// modifier, since the modifier leaves values that are not numbers as they are. Grouped modifiers
+/// are skipped as they do not always run.
+fn check_parsed_numbers(fields: &mut [FieldInfo]) {
+    for field in fields.iter_mut() {
+        let Some(decimal_separator) = field.modifiers.iter().find_map(|modifier| match modifier {
+            Modifier::ParseNumber { decimal_separator } => Some(*decimal_separator),
+            _ => None,
+        }) else {
+            continue;
+        };
+        let check = Validator::ParseNumber(ParseNumber {
+            decimal_separator,
+            ..Default::default()
+        });
+        let check = if field.is_list() {
+            Validator::Iter(vec![check])
+        } else {
+            check
+        };
+        field.validations.insert(0, check);
+    }
+}
+
+/// Resolves the fields named in `requires` validators to their idents and error names. The named
+/// fields must exist on the struct and be options.
+fn resolve_requires(fields: &mut [FieldInfo]) {
+    let siblings: Vec<(String, syn::Ident, String, bool)> = fields
+        .iter()
+        .map(|field| {
+            (
+                field.name.clone(),
+                field.field.ident.clone().unwrap(),
+                field.name(),
+                field.is_option(),
+            )
+        })
+        .collect();
+
+    for validator in fields
+        .iter_mut()
+        .flat_map(|field| field.validations.iter_mut())
+    {
+        let Validator::Requires(requires) = validator else {
+            continue;
+        };
+
+        for field in requires.fields.iter() {
+            let Some((_, ident, name, is_option)) =
+                siblings.iter().find(|(name, ..)| *name == field.value())
+            else {
+                abort!(
+                    field.span(),
+                    format!("Field `{}` does not exist on the struct", field.value())
+                )
+            };
+            if !is_option {
+                abort!(
+                    field.span(),
+                    format!(
+                        "Field `{}` in `requires` must be an `Option`",
+                        field.value()
+                    )
+                )
+            }
+            requires.dependents.push((ident.clone(), name.clone()));
+        }
+    }
+}
+
+/// Options specified with `#[validate(..)]` on the struct.
+#[derive(Default)]
+struct StructValidations {
+    /// Schema validation functions
+    schema: Vec<SchemaValidation>,
+    /// Boolean invariants specified with `predicate`
+    predicates: Vec<PredicateValidation>,
+    /// The name of the error code enum specified with `code_enum`
+    code_enum: Option<syn::Ident>,
+    /// Whether to generate the `rules` function
+    rules: bool,
+    /// Whether to generate the `JSON_SCHEMA` constant
+    json_schema: bool,
+    /// Whether to drop errors identical to an earlier one
+    dedup_errors: bool,
+    /// Whether to generate the `validate_flat` method
+    flat_errors: bool,
+    /// Prepended to the codes of the field validators
+    code_prefix: Option<String>,
+    /// The field whose value selects the validators of fields annotated with `variants`
+    tag: Option<syn::Ident>,
+    /// Where the time spent validating each field is reported
+    profile: Option<ProfileSink>,
+    /// Whether `None` fields are skipped by all of their validators
+    partial: bool,
+    /// The number of errors after which validation stops accumulating them
+    max_errors: Option<usize>,
+}
+
+/// Find if a struct has some schema validation and returns the info if so, along with any other
+/// struct level options.
+fn collect_struct_validation(attrs: &[syn::Attribute]) -> Result<StructValidations, syn::Error> {
+    let mut validations = StructValidations::default();
     let filtered = attrs
         .iter()
         .filter(|attribute| attribute.path().is_ident(VALIDATE));
 
     for attr in filtered {
         attr.parse_nested_meta(|meta| {
-            validations.push(SchemaValidation {
+            if meta.path.is_ident(CODE_ENUM) {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitStr>() else {
+                    return Err(meta.error(
+                        "code_enum must be a string literal containing the name of the enum",
+                    ));
+                };
+                validations.code_enum = Some(lit.parse::<syn::Ident>()?);
+                return Ok(());
+            }
+            if meta.path.is_ident(RULES) {
+                validations.rules = true;
+                return Ok(());
+            }
+            if meta.path.is_ident(JSON_SCHEMA) {
+                validations.json_schema = true;
+                return Ok(());
+            }
+            if meta.path.is_ident(CODE_PREFIX) {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitStr>() else {
+                    return Err(meta.error("code_prefix must be a string literal"));
+                };
+                validations.code_prefix = Some(lit.value());
+                return Ok(());
+            }
+            if meta.path.is_ident(PREDICATE) {
+                validations.predicates.push(parse_predicate(&meta)?);
+                return Ok(());
+            }
+            if meta.path.is_ident(TAG) {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitStr>() else {
+                    return Err(
+                        meta.error("tag must be a string literal containing the name of a field")
+                    );
+                };
+                validations.tag = Some(lit.parse::<syn::Ident>()?);
+                return Ok(());
+            }
+            // Shared rules are merged into the fields they apply to when collecting them
+            if meta.path.is_ident(APPLY) {
+                let content;
+                parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+                return Ok(());
+            }
+            if meta.path.is_ident(PROFILE) {
+                if meta.input.peek(syn::Token![=]) {
+                    let content = meta.value()?;
+                    let Ok(lit) = content.parse::<syn::LitStr>() else {
+                        return Err(meta.error(
+                            "profile must be a string literal containing the path to a callback",
+                        ));
+                    };
+                    validations.profile = Some(ProfileSink::Callback(lit.parse::<syn::Path>()?));
+                } else {
+                    validations.profile = Some(ProfileSink::Tracing);
+                }
+                return Ok(());
+            }
+            if meta.path.is_ident(DEDUP_ERRORS) {
+                validations.dedup_errors = true;
+                return Ok(());
+            }
+            if meta.path.is_ident(FLAT_ERRORS) {
+                validations.flat_errors = true;
+                return Ok(());
+            }
+            if meta.path.is_ident(PARTIAL) {
+                validations.partial = true;
+                return Ok(());
+            }
+            if meta.path.is_ident(MAX_ERRORS) {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitInt>() else {
+                    return Err(meta.error("max_errors must be an int literal"));
+                };
+                let max = lit.base10_parse::<usize>()?;
+                if max == 0 {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "max_errors must be greater than 0",
+                    ));
+                }
+                validations.max_errors = Some(max);
+                return Ok(());
+            }
+            validations.schema.push(SchemaValidation {
                 function: meta.path,
             });
             Ok(())
@@ -85,8 +473,95 @@ fn collect_struct_validation(
     Ok(validations)
 }
 
-pub fn collect_validations(field: &syn::Field) -> Vec<Validator> {
+/// Generates an enum of all the error codes the struct's field validators can produce.
+fn quote_code_enum(
+    input: &syn::DeriveInput,
+    code_enum: &syn::Ident,
+    fields: &[FieldInfo],
+) -> proc_macro2::TokenStream {
+    let mut codes: Vec<String> = vec![];
+
+    for code in fields
+        .iter()
+        .flat_map(|field| field.validations.iter())
+        .flat_map(Validator::codes)
+    {
+        if !codes.contains(&code) {
+            codes.push(code);
+        }
+    }
+
+    let variants = codes
+        .iter()
+        .map(|code| {
+            let variant = code
+                .split(|c: char| !c.is_alphanumeric())
+                .filter(|part| !part.is_empty())
+                .map(|part| {
+                    let mut chars = part.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                        .unwrap_or_default()
+                })
+                .collect::<String>();
+
+            syn::parse_str::<syn::Ident>(&variant).unwrap_or_else(|_| {
+                abort!(
+                    code_enum.span(),
+                    format!("The error code `{code}` cannot be converted to an enum variant")
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let vis = &input.vis;
+    let doc = format!(
+        " The error codes produced by the validators of `{}`.",
+        input.ident
+    );
+
+    quote!(
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+        #vis enum #code_enum {
+            #(
+                #[serde(rename = #codes)]
+                #variants,
+            )*
+        }
+
+        impl #code_enum {
+            /// Returns the error code as it appears in validation errors.
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    #(Self::#variants => #codes,)*
+                }
+            }
+        }
+
+        impl ::std::convert::TryFrom<&str> for #code_enum {
+            type Error = ::std::string::String;
+
+            /// Converts the error code, returning it as the error if it is not a known code.
+            fn try_from(code: &str) -> ::std::result::Result<Self, Self::Error> {
+                match code {
+                    #(#codes => ::std::result::Result::Ok(Self::#variants),)*
+                    _ => ::std::result::Result::Err(code.to_string()),
+                }
+            }
+        }
+    )
+}
+
+/// Returns the field's validators, the sibling bool field specified with `was_defaulted_field`, if any,
+/// and the values of the struct's tag field for which the validators run, specified with `variants`.
+pub fn collect_validations(
+    field: &syn::Field,
+) -> (Vec<Validator>, Option<syn::Ident>, Vec<String>) {
     let mut validators = vec![];
+    let mut was_defaulted = None;
+    let mut variants = vec![];
 
     for attr in field.attrs.iter() {
         if !attr.path().is_ident(VALIDATE) && !attr.path().is_ident(VALIDIFY) {
@@ -112,6 +587,64 @@ pub fn collect_validations(field: &syn::Field) -> Vec<Validator> {
                     Ok(())
                 })?;
                 validators.push(Validator::Iter(validators_iter));
+            } else if meta.path.is_ident(VALUES) {
+                let mut validators_values = vec![];
+                meta.parse_nested_meta(|meta| {
+                    parse_single_validation(meta, &mut validators_values)?;
+                    Ok(())
+                })?;
+                validators.push(Validator::Values(validators_values));
+            } else if meta.path.is_ident(EACH) {
+                let mut validators_each = vec![];
+                meta.parse_nested_meta(|meta| {
+                    parse_single_validation(meta, &mut validators_each)?;
+                    Ok(())
+                })?;
+                validators.push(Validator::Each(validators_each));
+            } else if meta.path.is_ident(WARN) {
+                let mut validators_warn = vec![];
+                meta.parse_nested_meta(|meta| {
+                    parse_single_validation(meta, &mut validators_warn)
+                })?;
+                if validators_warn
+                    .iter()
+                    .any(|v| matches!(v, Validator::Nested | Validator::Requires(_)))
+                {
+                    return Err(meta.error("`nested` and `requires` are not valid in `warn`"));
+                }
+                validators.push(Validator::Warn(validators_warn));
+            } else if meta.path.is_ident(EACH_ENTRY) {
+                let mut key = vec![];
+                let mut value = vec![];
+                meta.parse_nested_meta(|meta| {
+                    let validators = if meta.path.is_ident("key") {
+                        &mut key
+                    } else if meta.path.is_ident("value") {
+                        &mut value
+                    } else {
+                        return Err(meta.error(
+                            "Unrecognized each_entry parameter, accepted are: key, value",
+                        ));
+                    };
+                    meta.parse_nested_meta(|meta| parse_single_validation(meta, validators))
+                })?;
+                validators.push(Validator::EachEntry { key, value });
+            } else if meta.path.is_ident(WAS_DEFAULTED_FIELD) {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitStr>() else {
+                    return Err(meta.error("was_defaulted_field must be a string literal containing the name of a bool field"));
+                };
+                was_defaulted = Some(lit.parse::<syn::Ident>()?);
+            } else if meta.path.is_ident(VARIANTS) {
+                let content = meta.value()?;
+                let list;
+                syn::bracketed!(list in content);
+                let lits = list
+                    .parse_terminated(<syn::LitStr as syn::parse::Parse>::parse, syn::Token![,])?;
+                variants.extend(lits.iter().map(syn::LitStr::value));
+                if variants.is_empty() {
+                    return Err(meta.error("variants must contain at least one value"));
+                }
             } else {
                 parse_single_validation(meta, &mut validators)?;
             }
@@ -120,7 +653,10 @@ pub fn collect_validations(field: &syn::Field) -> Vec<Validator> {
         .unwrap_or_else(|e| abort!(e.span(), e));
     }
 
-    validators
+    // Stable, so the remaining validators keep their declaration order
+    validators.sort_by_key(|validator| matches!(validator, Validator::Custom(c) if c.run_last));
+
+    (validators, was_defaulted, variants)
 }
 
 fn parse_single_validation(
@@ -137,6 +673,16 @@ fn parse_single_validation(
         return Ok(());
     }
 
+    if meta.path.is_ident(FINITE) {
+        if meta.is_full_pattern() {
+            let validation = parse_finite_full(&meta)?;
+            validators.push(Validator::Finite(validation));
+        } else {
+            validators.push(Validator::Finite(Finite::default()));
+        }
+        return Ok(());
+    }
+
     if meta.path.is_ident(URL) {
         if meta.is_full_pattern() {
             let validation = parse_url_full(&meta)?;
@@ -239,6 +785,34 @@ fn parse_single_validation(
         return Ok(());
     }
 
+    if meta.path.is_ident(CONTAINS_WORD) {
+        if meta.is_single_lit("contains_word") {
+            let content;
+            parenthesized!(content in meta.input);
+            let Ok(lit) = content.parse::<syn::LitStr>() else {
+                return Err(meta.error(
+                    "Invalid value given for `contains_word` validation, must be a string literal or path",
+                ));
+            };
+            validators.push(Validator::ContainsWord(ContainsWord {
+                value: Some(ValueOrPath::Value(lit.value())),
+                ..Default::default()
+            }));
+        } else if meta.is_single_path("contains_word") {
+            let content;
+            parenthesized!(content in meta.input);
+            let path = content.parse::<syn::Path>()?;
+            validators.push(Validator::ContainsWord(ContainsWord {
+                value: Some(ValueOrPath::Path(path)),
+                ..Default::default()
+            }));
+        } else {
+            let validation = parse_contains_word_full(&meta)?;
+            validators.push(Validator::ContainsWord(validation));
+        }
+        return Ok(());
+    }
+
     if meta.path.is_ident(NON_CONTROL_CHAR) {
         if meta.is_full_pattern() {
             let validation = parse_non_control_char_full(&meta)?;
@@ -265,19 +839,14 @@ fn parse_single_validation(
     }
 
     if meta.path.is_ident(REGEX) {
-        if meta.is_single_path("regex") {
-            let content;
-            parenthesized!(content in meta.input);
-            let Ok(path) = content.parse::<syn::Path>() else {
-                return Err(
-                    meta.error("Invalid value given for `regex` validation, must be a path")
-                );
-            };
-            validators.push(Validator::Regex(Regex::new(path)));
-        } else {
-            let validation = parse_regex_full(&meta)?;
-            validators.push(Validator::Regex(validation));
-        }
+        let validation = parse_regex(&meta)?;
+        validators.push(Validator::Regex(validation));
+        return Ok(());
+    }
+
+    if meta.path.is_ident(EACH_LINE) {
+        let validation = parse_each_line_full(&meta)?;
+        validators.push(Validator::EachLine(validation));
         return Ok(());
     }
 
@@ -311,6 +880,39 @@ fn parse_single_validation(
         return Ok(());
     }
 
+    if meta.path.is_ident(REQUIRES) {
+        let validation = parse_requires(&meta)?;
+        validators.push(Validator::Requires(validation));
+        return Ok(());
+    }
+
+    if meta.path.is_ident(NESTED) {
+        validators.push(Validator::Nested);
+        return Ok(());
+    }
+
+    if meta.path.is_ident(TRY_INTO) {
+        let validation = parse_try_into(&meta)?;
+        validators.push(Validator::TryInto(validation));
+        return Ok(());
+    }
+
+    if meta.path.is_ident(VALID_RANGE) {
+        if meta.is_full_pattern() {
+            let validation = parse_valid_range_full(&meta)?;
+            validators.push(Validator::ValidRange(validation));
+        } else {
+            validators.push(Validator::ValidRange(ValidRange::default()));
+        }
+        return Ok(());
+    }
+
+    if meta.path.is_ident(RANGE_LEN) {
+        let validation = parse_range_len(&meta)?;
+        validators.push(Validator::RangeLen(validation));
+        return Ok(());
+    }
+
     if meta.path.is_ident(IS_IN) {
         if meta.is_single_path("in") {
             let content;
@@ -345,6 +947,12 @@ fn parse_single_validation(
         return Ok(());
     }
 
+    if meta.path.is_ident(ONE_OF) {
+        let validation = parse_one_of(&meta)?;
+        validators.push(Validator::OneOf(validation));
+        return Ok(());
+    }
+
     if meta.path.is_ident(IP) {
         if meta.is_full_pattern() {
             let validation = parse_ip_full(&meta)?;
@@ -361,5 +969,25 @@ fn parse_single_validation(
         return Ok(());
     }
 
+    if meta.path.is_ident(FUTURE) {
+        if meta.is_full_pattern() {
+            let validation = parse_future_full(&meta)?;
+            validators.push(Validator::Future(validation));
+        } else {
+            validators.push(Validator::Future(Future::default()));
+        }
+        return Ok(());
+    }
+
+    if meta.path.is_ident(PAST) {
+        if meta.is_full_pattern() {
+            let validation = parse_past_full(&meta)?;
+            validators.push(Validator::Past(validation));
+        } else {
+            validators.push(Validator::Past(Past::default()));
+        }
+        return Ok(());
+    }
+
     Err(meta.error("Unrecognized validate parameter"))
 }
@@ -1,12 +1,17 @@
 use super::validation::{
-    Contains, CreditCard, Custom, Email, In, Ip, Length, MustMatch, NonControlChar, Phone, Range,
-    Regex, Required, Time, TimeMultiplier, TimeOp, Url,
+    Contains, ContainsWord, CreditCard, Custom, EachLine, Email, Finite, Future, In, Ip, Length,
+    MustMatch, NonControlChar, OneOf, Past, Phone, PredicateValidation, Range, RangeBound,
+    RangeLen, Regex, Required, Requires, Time, TimeMultiplier, TimeOp, TryConvert, Url, ValidRange,
 };
+use super::ValidationMeta;
 use proc_macro2::Span;
 use proc_macro_error::abort;
 use quote::quote;
 use quote::ToTokens;
-use syn::{meta::ParseNestedMeta, punctuated::Punctuated, LitBool, LitFloat, LitInt, LitStr};
+use syn::{
+    meta::ParseNestedMeta, parenthesized, punctuated::Punctuated, spanned::Spanned, LitBool,
+    LitFloat, LitInt, LitStr,
+};
 
 /// Used to encapsulate either a literal value or a path in annotations.
 #[derive(Debug, Clone, PartialEq)]
@@ -61,7 +66,8 @@ macro_rules! parser {
     };
 }
 
-/// Used by individual validations to extract the code and message from the annotations.
+/// Used by individual validations to extract the code and message from the annotations, as well
+/// as whether the code opts out of the struct's `code_prefix`.
 macro_rules! code_and_message {
     ($validation:ident, $meta:ident) => {
         if $meta.path.is_ident("message") {
@@ -81,16 +87,183 @@ macro_rules! code_and_message {
             }
             return Ok(());
         }
+
+        if $meta.path.is_ident("no_code_prefix") {
+            $validation.no_code_prefix = true;
+            return Ok(());
+        }
     };
 }
 
-parser!(parse_email_full, Email);
 parser!(parse_url_full, Url);
 parser!(parse_non_control_char_full, NonControlChar);
 parser!(parse_phone_full, Phone);
+parser!(parse_finite_full, Finite);
+parser!(parse_valid_range_full, ValidRange);
+
+/// Parses `try_into(type = "Color")`, the type being any `TryFrom<FieldType>`.
+pub fn parse_try_into(meta: &ParseNestedMeta) -> Result<TryConvert, syn::Error> {
+    let mut validation = TryConvert::default();
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("type") {
+            let content = meta.value()?;
+            let Ok(lit) = content.parse::<LitStr>() else {
+                return Err(meta.error("try_into type must be a string literal"));
+            };
+            validation.ty = Some(lit.parse::<syn::Type>()?);
+            return Ok(());
+        }
+
+        code_and_message!(validation, meta);
+
+        Err(meta.error("Unrecognized try_into parameter, accepted are: type, code, message"))
+    })?;
+
+    if validation.ty.is_none() {
+        return Err(meta.error("try_into must contain a type"));
+    }
+
+    Ok(validation)
+}
 parser!(parse_credit_card_full, CreditCard);
 parser!(parse_required_full, Required);
 
+pub fn parse_predicate(meta: &ParseNestedMeta) -> Result<PredicateValidation, syn::Error> {
+    let mut function = None;
+    let mut code = None;
+    let mut message = None;
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("function") {
+            let content = meta.value()?;
+            function = Some(match content.parse::<LitStr>() {
+                Ok(lit) => lit.parse::<syn::Path>()?,
+                Err(_) => content.parse::<syn::Path>()?,
+            });
+            return Ok(());
+        }
+
+        if meta.path.is_ident("code") {
+            let content = meta.value()?;
+            match content.parse::<LitStr>() {
+                Ok(lit) => code = Some(lit.value()),
+                Err(_) => return Err(meta.error("Code must be a string literal")),
+            }
+            return Ok(());
+        }
+
+        if meta.path.is_ident("message") {
+            let content = meta.value()?;
+            match content.parse::<LitStr>() {
+                Ok(lit) => message = Some(lit.value()),
+                Err(_) => return Err(meta.error("Message must be a string literal")),
+            }
+            return Ok(());
+        }
+
+        Err(meta.error("Unrecognized predicate parameter, accepted are: function, code, message"))
+    })?;
+
+    let Some(function) = function else {
+        return Err(meta.error("predicate must specify a function"));
+    };
+
+    Ok(PredicateValidation {
+        function,
+        code,
+        message,
+    })
+}
+
+/// Parses `requires(["a", "b"])` or `requires(fields = ["a", "b"], code = .., message = ..)`.
+pub fn parse_requires(meta: &ParseNestedMeta) -> Result<Requires, syn::Error> {
+    let mut validation = Requires::default();
+
+    let fork = meta.input.fork();
+    let content;
+    parenthesized!(content in fork);
+
+    if content.peek(syn::token::Bracket) {
+        let content;
+        parenthesized!(content in meta.input);
+        validation.fields = parse_lit_str_list(&content)?;
+    } else {
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("fields") {
+                validation.fields = parse_lit_str_list(meta.value()?)?;
+                return Ok(());
+            }
+
+            code_and_message!(validation, meta);
+
+            Err(meta.error("Unrecognized requires parameter, accepted are: fields, code, message"))
+        })?;
+    }
+
+    if validation.fields.is_empty() {
+        return Err(meta.error("requires must contain at least one field"));
+    }
+
+    Ok(validation)
+}
+
+/// Parses `["a", "b"]`.
+fn parse_lit_str_list(content: syn::parse::ParseStream) -> Result<Vec<LitStr>, syn::Error> {
+    let list;
+    syn::bracketed!(list in content);
+    let items = list.parse_terminated(<LitStr as syn::parse::Parse>::parse, syn::Token![,])?;
+    Ok(items.into_iter().collect())
+}
+
+pub fn parse_email_full(meta: &ParseNestedMeta) -> Result<Email, syn::Error> {
+    let mut validation = Email::default();
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("require_tld") {
+            validation.require_tld = true;
+            return Ok(());
+        }
+
+        code_and_message!(validation, meta);
+
+        Err(meta.error("Unrecognized email parameter, accepted are: require_tld, code, message"))
+    })?;
+
+    Ok(validation)
+}
+
+/// Parses `range_len(min = 1, max = 1000)`, bounding the number of values in a range field.
+pub fn parse_range_len(meta: &ParseNestedMeta) -> Result<RangeLen, syn::Error> {
+    let mut validation = RangeLen::default();
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("min") || meta.path.is_ident("max") {
+            let content = meta.value()?;
+            let Ok(lit) = content.parse::<LitInt>() else {
+                return Err(meta.error("range_len parameter must be an int literal"));
+            };
+            let bound = Some(lit.base10_parse::<u64>()?);
+            if meta.path.is_ident("min") {
+                validation.min = bound;
+            } else {
+                validation.max = bound;
+            }
+            return Ok(());
+        }
+
+        code_and_message!(validation, meta);
+
+        Err(meta.error("Unrecognized range_len parameter, accepted are: min, max, code, message"))
+    })?;
+
+    if validation.min.is_none() && validation.max.is_none() {
+        return Err(meta.error("range_len must contain at least one of min or max"));
+    }
+
+    Ok(validation)
+}
+
 pub fn parse_length(meta: &ParseNestedMeta) -> Result<Length, syn::Error> {
     let mut validation = Length::default();
 
@@ -140,43 +313,111 @@ pub fn parse_length(meta: &ParseNestedMeta) -> Result<Length, syn::Error> {
             return Ok(());
         }
 
+        if meta.path.is_ident("min_from") {
+            validation.min_from = Some(parse_length_from(&meta)?);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("max_from") {
+            validation.max_from = Some(parse_length_from(&meta)?);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("equal_from") {
+            validation.equal_from = Some(parse_length_from(&meta)?);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("none_is_zero") {
+            validation.none_is_zero = true;
+            return Ok(());
+        }
+
+        if meta.path.is_ident("ignore_whitespace") {
+            validation.ignore_whitespace = true;
+            return Ok(());
+        }
+
         code_and_message!(validation, meta);
 
         Err(meta.error("Unrecognized length parameter"))
     })?;
 
+    if validation.min.is_some() && validation.min_from.is_some() {
+        return Err(meta.error("min and min_from cannot both be set"));
+    }
+
+    if validation.max.is_some() && validation.max_from.is_some() {
+        return Err(meta.error("max and max_from cannot both be set"));
+    }
+
+    if validation.equal.is_some() && validation.equal_from.is_some() {
+        return Err(meta.error("equal and equal_from cannot both be set"));
+    }
+
+    let has_bounds = validation.min.is_some()
+        || validation.min_from.is_some()
+        || validation.max.is_some()
+        || validation.max_from.is_some();
+
+    if (validation.equal.is_some() || validation.equal_from.is_some()) && has_bounds {
+        return Err(meta.error("equal parameter cannot be set if max or min exist"));
+    }
+
     Ok(validation)
 }
 
+/// Parses a length bound given as a string literal path to a lazily initialized value,
+/// i.e. `max_from = "config::MAX_NAME"`.
+fn parse_length_from(meta: &ParseNestedMeta) -> Result<syn::Path, syn::Error> {
+    let content = meta.value()?;
+    match content.parse::<LitStr>() {
+        Ok(lit) => lit.parse::<syn::Path>(),
+        Err(_) => Err(meta.error("Lazy length bounds must be a string literal path")),
+    }
+}
+
 pub fn parse_range(meta: &ParseNestedMeta) -> Result<Range, syn::Error> {
     let mut validation = Range::default();
 
     meta.parse_nested_meta(|meta| {
         if meta.path.is_ident("min") {
             let content = meta.value()?;
-            match content.parse::<LitFloat>() {
-                Ok(lit) => validation.min = Some(ValueOrPath::Value(lit.base10_parse::<f64>()?)),
-                Err(_) => match content.parse::<syn::Path>() {
-                    Ok(path) => validation.min = Some(ValueOrPath::Path(path)),
-                    Err(_) => {
-                        return Err(meta.error("Range parameter must be a float literal or path"))
-                    }
-                },
+            validation.min = Some(parse_range_limit(&meta, content)?);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("min_message") {
+            let content = meta.value()?;
+            match content.parse::<LitStr>() {
+                Ok(lit) => validation.min_message = Some(lit.value()),
+                Err(_) => return Err(meta.error("Message must be a string literal")),
             }
             return Ok(());
         }
 
         if meta.path.is_ident("max") {
             let content = meta.value()?;
-            match content.parse::<LitFloat>() {
-                Ok(lit) => validation.max = Some(ValueOrPath::Value(lit.base10_parse::<f64>()?)),
-                Err(_) => match content.parse::<syn::Path>() {
-                    Ok(path) => validation.max = Some(ValueOrPath::Path(path)),
-                    Err(_) => {
-                        return Err(meta.error("Range parameter must be a float literal or path"))
-                    }
-                },
+            validation.max = Some(parse_range_limit(&meta, content)?);
+            return Ok(());
+        }
+
+        if meta.path.is_ident("max_message") {
+            let content = meta.value()?;
+            match content.parse::<LitStr>() {
+                Ok(lit) => validation.max_message = Some(lit.value()),
+                Err(_) => return Err(meta.error("Message must be a string literal")),
+            }
+            return Ok(());
+        }
+
+        if meta.path.is_ident("step") {
+            let content = meta.value()?;
+            let step = parse_range_bound(&meta, content)?;
+            if matches!(step, ValueOrPath::Value(v) if v <= 0.) {
+                return Err(meta.error("step must be greater than 0"));
             }
+            validation.step = Some(step);
             return Ok(());
         }
 
@@ -185,9 +426,154 @@ pub fn parse_range(meta: &ParseNestedMeta) -> Result<Range, syn::Error> {
         Err(meta.error("Unrecognized range parameter"))
     })?;
 
+    if validation.is_char() {
+        let mixed = [&validation.min, &validation.max]
+            .into_iter()
+            .flatten()
+            .any(|bound| !matches!(bound, RangeBound::Char(_)));
+        if mixed {
+            return Err(meta.error("char range bounds cannot be mixed with other bounds"));
+        }
+        if validation.step.is_some() {
+            return Err(meta.error("step cannot be used with char ranges"));
+        }
+    }
+
     Ok(validation)
 }
 
+/// Parses a range `min` or `max`, which can also be `"now"` with an optional offset in seconds,
+/// e.g. `"now-3600"`, or a char literal.
+fn parse_range_limit(
+    meta: &ParseNestedMeta,
+    content: syn::parse::ParseStream,
+) -> Result<RangeBound, syn::Error> {
+    if content.fork().parse::<syn::LitChar>().is_ok() {
+        return Ok(RangeBound::Char(content.parse::<syn::LitChar>()?.value()));
+    }
+
+    if content.fork().parse::<LitStr>().is_ok() {
+        let lit = content.parse::<LitStr>()?;
+        let value = lit.value();
+        let Some(offset) = value.trim().strip_prefix("now") else {
+            return Err(meta.error("Range string parameter must be \"now\", optionally with an offset, e.g. \"now-3600\""));
+        };
+        let offset = offset.replace(' ', "");
+        if offset.is_empty() {
+            return Ok(RangeBound::Now(0));
+        }
+        if !offset.starts_with(['+', '-']) {
+            return Err(meta.error("now offset must start with + or -"));
+        }
+        return match offset.parse::<i64>() {
+            Ok(offset) => Ok(RangeBound::Now(offset)),
+            Err(_) => Err(meta.error("now offset must be an integer amount of seconds")),
+        };
+    }
+
+    match parse_range_bound(meta, content)? {
+        ValueOrPath::Value(value) => Ok(RangeBound::Value(value)),
+        ValueOrPath::Path(path) => Ok(RangeBound::Path(path)),
+    }
+}
+
+/// Parses a range bound from a float or int literal, or a path.
+fn parse_range_bound(
+    meta: &ParseNestedMeta,
+    content: syn::parse::ParseStream,
+) -> Result<ValueOrPath<f64>, syn::Error> {
+    if content.fork().parse::<LitFloat>().is_ok() {
+        let lit = content.parse::<LitFloat>()?;
+        return Ok(ValueOrPath::Value(lit.base10_parse::<f64>()?));
+    }
+
+    if content.fork().parse::<LitInt>().is_ok() {
+        let lit = content.parse::<LitInt>()?;
+        return Ok(ValueOrPath::Value(lit.base10_parse::<f64>()?));
+    }
+
+    match content.parse::<syn::Path>() {
+        Ok(path) => Ok(ValueOrPath::Path(path)),
+        Err(_) => Err(meta.error("Range parameter must be a float or int literal or path")),
+    }
+}
+
+pub fn parse_future_full(meta: &ParseNestedMeta) -> Result<Future, syn::Error> {
+    let mut validation = Future::default();
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("grace") {
+            validation.grace = parse_grace(&meta)?;
+            return Ok(());
+        }
+
+        code_and_message!(validation, meta);
+
+        Err(meta.error("Unrecognized future parameter, accepted are: grace, code, message"))
+    })?;
+
+    Ok(validation)
+}
+
+pub fn parse_past_full(meta: &ParseNestedMeta) -> Result<Past, syn::Error> {
+    let mut validation = Past::default();
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("grace") {
+            validation.grace = parse_grace(&meta)?;
+            return Ok(());
+        }
+
+        code_and_message!(validation, meta);
+
+        Err(meta.error("Unrecognized past parameter, accepted are: grace, code, message"))
+    })?;
+
+    Ok(validation)
+}
+
+/// The largest grace period in seconds a `chrono::Duration` can hold.
+const MAX_GRACE: i64 = i64::MAX / 1000;
+
+/// Parses a grace period such as `"30s"` or `"5m"` into seconds.
+fn parse_grace(meta: &ParseNestedMeta) -> Result<i64, syn::Error> {
+    let content = meta.value()?;
+    let Ok(lit) = content.parse::<LitStr>() else {
+        return Err(meta.error("grace must be a string literal, i.e. `grace = \"5m\"`"));
+    };
+
+    let value = lit.value();
+    let split = value
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 60 * 60 * 24,
+        "w" => 60 * 60 * 24 * 7,
+        _ => abort!(
+            lit.span(),
+            "Invalid grace unit, accepted are: s, m, h, d, w, i.e. `grace = \"5m\"`"
+        ),
+    };
+
+    let Ok(amount) = amount.parse::<i64>() else {
+        abort!(
+            lit.span(),
+            "Invalid grace amount, must be a positive integer"
+        )
+    };
+
+    // Durations are stored in milliseconds, so larger periods cannot be represented
+    match amount.checked_mul(multiplier) {
+        Some(grace) if grace <= MAX_GRACE => Ok(grace),
+        _ => abort!(lit.span(), "grace period is too large"),
+    }
+}
+
 pub fn parse_contains_full(meta: &ParseNestedMeta, not: bool) -> Result<Contains, syn::Error> {
     let mut validation = Contains {
         not,
@@ -223,11 +609,47 @@ pub fn parse_contains_full(meta: &ParseNestedMeta, not: bool) -> Result<Contains
     Ok(validation)
 }
 
+pub fn parse_contains_word_full(meta: &ParseNestedMeta) -> Result<ContainsWord, syn::Error> {
+    let mut validation = ContainsWord::default();
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("value") {
+            let content = meta.value()?;
+            match content.parse::<LitStr>() {
+                Ok(lit) => validation.value = Some(ValueOrPath::Value(lit.value())),
+                Err(_) => match content.parse::<syn::Path>() {
+                    Ok(path) => validation.value = Some(ValueOrPath::Path(path)),
+                    Err(_) => {
+                        return Err(
+                            meta.error("contains_word parameter must be a string literal or path")
+                        )
+                    }
+                },
+            }
+            return Ok(());
+        }
+
+        code_and_message!(validation, meta);
+
+        Err(meta.error("Unrecognized contains_word parameter, accepted are: value, code, message"))
+    })?;
+
+    if validation.value.is_none() {
+        abort!(
+            meta.input.span(),
+            "contains_word validation must have a value"
+        )
+    }
+
+    Ok(validation)
+}
+
 pub fn parse_must_match_full(meta: &ParseNestedMeta) -> Result<MustMatch, syn::Error> {
     let mut validation = MustMatch {
         value: syn::Ident::new("BAD_____NO_____BAD", Span::call_site()),
         code: None,
         message: None,
+        no_code_prefix: false,
     };
 
     meta.parse_nested_meta(|meta| {
@@ -258,20 +680,65 @@ pub fn parse_must_match_full(meta: &ParseNestedMeta) -> Result<MustMatch, syn::E
     Ok(validation)
 }
 
+/// Parses the path to a custom function, given either directly or as a string literal, e.g.
+/// `function = crate::normalize::email` or `function = "crate::normalize::email"`.
+pub fn parse_function_path(content: syn::parse::ParseStream) -> Result<syn::Path, syn::Error> {
+    if content.peek(LitStr) {
+        return content.parse::<LitStr>()?.parse::<syn::Path>();
+    }
+    content.parse::<syn::Path>()
+}
+
 pub fn parse_custom_full(meta: &ParseNestedMeta) -> Result<Custom, syn::Error> {
     let mut validation = Custom {
         path: syn::Path {
             leading_colon: None,
             segments: Punctuated::new(),
         },
+        timeout_ms: None,
+        groups: vec![],
+        run_last: false,
+        by_value: false,
         code: None,
         message: None,
+        no_code_prefix: false,
     };
 
     meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("groups") {
+            let content = meta.value()?;
+            let list;
+            syn::bracketed!(list in content);
+            let groups = list.parse_terminated(<LitStr as syn::parse::Parse>::parse, syn::Token![,])?;
+            validation.groups.extend(groups.iter().map(LitStr::value));
+            if validation.groups.is_empty() {
+                return Err(meta.error("groups must contain at least one group"));
+            }
+            return Ok(());
+        }
+
+        if meta.path.is_ident("run_last") {
+            validation.run_last = true;
+            return Ok(());
+        }
+
+        if meta.path.is_ident("by_value") {
+            validation.by_value = true;
+            return Ok(());
+        }
+
+        if meta.path.is_ident("timeout_ms") {
+            let content = meta.value()?;
+            match content.parse::<LitInt>() {
+                Ok(lit) => validation.timeout_ms = Some(lit.base10_parse::<u64>()?),
+                Err(_) => return Err(meta.error("timeout_ms must be an int literal")),
+            }
+            return Ok(());
+        }
+
         if meta.path.is_ident("function") {
             let content = meta.value()?;
-            match content.parse::<syn::Path>() {
+            match parse_function_path(content) {
                 Ok(path) => {
                     validation.path = path;
                 }
@@ -284,7 +751,9 @@ pub fn parse_custom_full(meta: &ParseNestedMeta) -> Result<Custom, syn::Error> {
 
         code_and_message!(validation, meta);
 
-        Err(meta.error("Unrecognized custom parameter, accepted are: path, code, message"))
+        Err(meta.error(
+            "Unrecognized custom parameter, accepted are: function, timeout_ms, groups, run_last, by_value, code, message",
+        ))
     })?;
 
     if validation.path.segments.is_empty() {
@@ -294,22 +763,66 @@ pub fn parse_custom_full(meta: &ParseNestedMeta) -> Result<Custom, syn::Error> {
     Ok(validation)
 }
 
+/// Parses any of `regex("pattern")`, `regex(PATH)` and `regex(pattern = "..", ..)`.
+pub fn parse_regex(meta: &ParseNestedMeta) -> Result<Regex, syn::Error> {
+    if meta.is_single_lit("regex") {
+        let content;
+        parenthesized!(content in meta.input);
+        let Ok(pattern) = content.parse::<LitStr>() else {
+            return Err(meta.error(
+                "Invalid value given for `regex` validation, must be a path or string literal",
+            ));
+        };
+        return Ok(Regex::new(ValueOrPath::Value(parse_regex_pattern(
+            &pattern,
+        ))));
+    }
+
+    if meta.is_single_path("regex") {
+        let content;
+        parenthesized!(content in meta.input);
+        let Ok(path) = content.parse::<syn::Path>() else {
+            return Err(meta.error("Invalid value given for `regex` validation, must be a path"));
+        };
+        return Ok(Regex::new(ValueOrPath::Path(path)));
+    }
+
+    parse_regex_full(meta)
+}
+
+pub fn parse_each_line_full(meta: &ParseNestedMeta) -> Result<EachLine, syn::Error> {
+    let mut validation = EachLine::default();
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("regex") {
+            validation.regex = Some(parse_regex(&meta)?);
+            return Ok(());
+        }
+
+        code_and_message!(validation, meta);
+
+        Err(meta.error("Unrecognized each_line parameter, accepted are: regex, code, message"))
+    })?;
+
+    if validation.regex.is_none() {
+        return Err(meta.error("each_line validation must have a regex"));
+    }
+
+    Ok(validation)
+}
+
 pub fn parse_regex_full(meta: &ParseNestedMeta) -> Result<Regex, syn::Error> {
-    let mut validation = Regex {
-        path: syn::Path {
-            leading_colon: None,
-            segments: Punctuated::new(),
-        },
-        code: None,
-        message: None,
-    };
+    let mut validation = Regex::new(ValueOrPath::Path(syn::Path {
+        leading_colon: None,
+        segments: Punctuated::new(),
+    }));
 
     meta.parse_nested_meta(|meta| {
         if meta.path.is_ident("path") {
             let content = meta.value()?;
             match content.parse::<syn::Path>() {
                 Ok(path) => {
-                    validation.path = path;
+                    validation.regex = ValueOrPath::Path(path);
                 }
                 Err(_) => return Err(meta.error(
                     "regex value must be a path to a function that takes in the type of the field",
@@ -318,18 +831,51 @@ pub fn parse_regex_full(meta: &ParseNestedMeta) -> Result<Regex, syn::Error> {
             return Ok(());
         }
 
+        if meta.path.is_ident("pattern") {
+            let content = meta.value()?;
+            match content.parse::<LitStr>() {
+                Ok(pattern) => {
+                    validation.regex = ValueOrPath::Value(parse_regex_pattern(&pattern));
+                }
+                Err(_) => return Err(meta.error("regex pattern must be a string literal")),
+            }
+            return Ok(());
+        }
+
+        if meta.path.is_ident("lowercase_first") {
+            validation.lowercase_first = true;
+            return Ok(());
+        }
+
+        if meta.path.is_ident("trim_first") {
+            validation.trim_first = true;
+            return Ok(());
+        }
+
         code_and_message!(validation, meta);
 
-        Err(meta.error("Unrecognized regex parameter, accepted are: path, code, message"))
+        Err(meta.error("Unrecognized regex parameter, accepted are: path, pattern, lowercase_first, trim_first, code, message"))
     })?;
 
-    if validation.path.segments.is_empty() {
-        abort!(meta.input.span(), "regex validation must contain a path")
+    if matches!(validation.regex, ValueOrPath::Path(ref path) if path.segments.is_empty()) {
+        abort!(
+            meta.input.span(),
+            "regex validation must contain a path or pattern"
+        )
     }
 
     Ok(validation)
 }
 
+/// Checks the pattern compiles so invalid regexes are caught at compile time.
+pub fn parse_regex_pattern(pattern: &LitStr) -> String {
+    let value = pattern.value();
+    if let Err(e) = regex::Regex::new(&value) {
+        abort!(pattern.span(), format!("Invalid regex pattern: {e}"))
+    }
+    value
+}
+
 pub fn parse_in_full(meta: &ParseNestedMeta, not: bool) -> Result<In, syn::Error> {
     let mut validation = In::new(not);
 
@@ -361,6 +907,86 @@ pub fn parse_in_full(meta: &ParseNestedMeta, not: bool) -> Result<In, syn::Error
     Ok(validation)
 }
 
+/// Parses `one_of(["a", "b"], case_insensitive, code = "..", message = "..")` and
+/// `one_of(enum_discriminants = "Enum", code = "..", message = "..")`.
+pub fn parse_one_of(meta: &ParseNestedMeta) -> Result<OneOf, syn::Error> {
+    let mut validation = OneOf::default();
+
+    let content;
+    syn::parenthesized!(content in meta.input);
+
+    if content.peek(syn::token::Bracket) {
+        let values = content.parse::<syn::ExprArray>()?;
+
+        for value in values.elems {
+            let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(ref lit),
+                ..
+            }) = value
+            else {
+                return Err(syn::Error::new(
+                    value.span(),
+                    "one_of values must be string literals",
+                ));
+            };
+            validation.values.push(lit.value());
+        }
+
+        if validation.values.is_empty() {
+            return Err(meta.error("one_of must contain at least one value"));
+        }
+
+        if !content.is_empty() {
+            content.parse::<syn::Token![,]>()?;
+        }
+    }
+
+    while !content.is_empty() {
+        let param = content.parse::<syn::Ident>()?;
+
+        if param == "case_insensitive" {
+            validation.case_insensitive = true;
+        } else if param == "enum_discriminants" || param == "code" || param == "message" {
+            content.parse::<syn::Token![=]>()?;
+            let Ok(lit) = content.parse::<LitStr>() else {
+                return Err(syn::Error::new(
+                    param.span(),
+                    format!("{param} must be a string literal"),
+                ));
+            };
+            if param == "enum_discriminants" {
+                validation.enum_discriminants = Some(lit.parse::<syn::Path>()?);
+            } else if param == "code" {
+                validation.code = Some(lit.value());
+            } else {
+                validation.message = Some(lit.value());
+            }
+        } else {
+            return Err(syn::Error::new(
+                param.span(),
+                "Unrecognized one_of parameter, accepted are: enum_discriminants, case_insensitive, code, message",
+            ));
+        }
+
+        if !content.is_empty() {
+            content.parse::<syn::Token![,]>()?;
+        }
+    }
+
+    match (validation.values.is_empty(), &validation.enum_discriminants) {
+        (true, None) => Err(meta.error(
+            "one_of must contain an array of string literals, i.e. `one_of([\"a\", \"b\"])`, or `enum_discriminants`",
+        )),
+        (false, Some(_)) => Err(meta.error(
+            "one_of cannot contain both a list of values and enum_discriminants",
+        )),
+        (true, Some(_)) if validation.case_insensitive => Err(meta.error(
+            "case_insensitive cannot be used with enum_discriminants",
+        )),
+        _ => Ok(validation),
+    }
+}
+
 pub fn parse_ip_full(meta: &ParseNestedMeta) -> Result<Ip, syn::Error> {
     let mut validation = Ip::default();
 
@@ -8,35 +8,195 @@ pub struct SchemaValidation {
     pub function: syn::Path,
 }
 
+/// A boolean invariant over the whole struct, adding a schema error when it does not hold.
+#[derive(Debug)]
+pub struct PredicateValidation {
+    pub function: syn::Path,
+    pub code: Option<String>,
+    pub message: Option<String>,
+}
+
+impl PredicateValidation {
+    pub fn code(&self) -> &str {
+        self.code.as_deref().unwrap_or("predicate")
+    }
+}
+
+/// Where the time spent validating each field is reported with `profile`.
+#[derive(Debug)]
+pub enum ProfileSink {
+    /// A `tracing` event, requires the `tracing` feature.
+    Tracing,
+    /// A function taking the field name and the elapsed `Duration`.
+    Callback(syn::Path),
+}
+
 /// Trait implemented by validators to output validation codes and messages.
 pub trait Describe {
     fn code(&self) -> &str;
 
     fn message(&self) -> Option<&str>;
+
+    /// The code set with `code = ".."`, if any.
+    fn code_mut(&mut self) -> &mut Option<String>;
+
+    /// Returns `true` if the validator opted out of the struct's `code_prefix`.
+    fn no_code_prefix(&self) -> bool;
+
+    /// Prepends the prefix to the code, unless the validator opted out with `no_code_prefix`.
+    fn prefix_code(&mut self, prefix: &str) {
+        if !self.no_code_prefix() {
+            let code = format!("{prefix}{}", self.code());
+            *self.code_mut() = Some(code);
+        }
+    }
 }
 
 /// Contains all the validators that can be used
 #[derive(Debug)]
 pub enum Validator {
     Iter(Vec<Self>),
+    /// Validators applied to each of the values of a map, located by their keys.
+    Values(Vec<Self>),
+    /// Validators applied to each of the elements of a tuple, located by their index.
+    Each(Vec<Self>),
+    /// Validators applied to the keys and values of a list of pairs, located by the index of the
+    /// pair followed by `key` or `value`.
+    EachEntry {
+        key: Vec<Self>,
+        value: Vec<Self>,
+    },
+    /// Validators whose errors are reported as warnings by `validate_report` instead of failing
+    /// validation.
+    Warn(Vec<Self>),
     Email(Email),
     Url(Url),
+    /// Added to fields with the `parse_number` modifier, rejecting values it leaves as they are.
+    ParseNumber(ParseNumber),
     CreditCard(CreditCard),
     Phone(Phone),
     Custom(Custom),
     Range(Range),
+    Finite(Finite),
+    TryInto(TryConvert),
+    /// Checks that the start of a `Range` or `RangeInclusive` is not after its end.
+    ValidRange(ValidRange),
+    /// Bounds the number of values in a `Range` or `RangeInclusive`.
+    RangeLen(RangeLen),
     Length(Length),
     NonControlCharacter(NonControlChar),
     Required(Required),
+    /// Requires the named sibling fields to be present when the field is present.
+    Requires(Requires),
     MustMatch(MustMatch),
     Regex(Regex),
+    EachLine(EachLine),
     Contains(Contains),
+    ContainsWord(ContainsWord),
     Time(Time),
     In(In),
+    OneOf(OneOf),
     Ip(Ip),
+    Future(Future),
+    Past(Past),
     Nested,
 }
 
+impl Validator {
+    /// Returns the error codes the validator can produce. Codes of errors returned by custom
+    /// functions are not known at compile time and are only included when overridden.
+    pub fn codes(&self) -> Vec<String> {
+        match self {
+            Validator::Iter(validators)
+            | Validator::Values(validators)
+            | Validator::Each(validators)
+            | Validator::Warn(validators) => validators.iter().flat_map(Self::codes).collect(),
+            Validator::EachEntry { key, value } => key
+                .iter()
+                .chain(value.iter())
+                .flat_map(Self::codes)
+                .collect(),
+            Validator::Email(v) => vec![v.code().to_string()],
+            Validator::Url(v) => vec![v.code().to_string()],
+            Validator::ParseNumber(v) => vec![v.code().to_string()],
+            Validator::CreditCard(v) => vec![v.code().to_string()],
+            Validator::Phone(v) => vec![v.code().to_string()],
+            Validator::Custom(v) => v
+                .code
+                .iter()
+                .cloned()
+                .chain(v.timeout_ms.map(|_| "custom_timeout".to_string()))
+                .collect(),
+            Validator::Range(v) => vec![v.code().to_string()],
+            Validator::Finite(v) => vec![v.code().to_string()],
+            Validator::TryInto(v) => vec![v.code().to_string()],
+            Validator::ValidRange(v) => vec![v.code().to_string()],
+            Validator::RangeLen(v) => vec![v.code().to_string()],
+            Validator::Length(v) => vec![v.code().to_string()],
+            Validator::NonControlCharacter(v) => vec![v.code().to_string()],
+            Validator::Required(v) => vec![v.code().to_string()],
+            Validator::Requires(v) => vec![v.code().to_string()],
+            Validator::MustMatch(v) => vec![v.code().to_string()],
+            Validator::Regex(v) => vec![v.code().to_string()],
+            Validator::EachLine(v) => vec![v.code().to_string()],
+            Validator::Contains(v) => vec![v.code().to_string()],
+            Validator::ContainsWord(v) => vec![v.code().to_string()],
+            Validator::Time(v) => vec![v.code().to_string()],
+            Validator::In(v) => vec![v.code().to_string()],
+            Validator::OneOf(v) => vec![v.code().to_string()],
+            Validator::Ip(v) => vec![v.code().to_string()],
+            Validator::Future(v) => vec![v.code().to_string()],
+            Validator::Past(v) => vec![v.code().to_string()],
+            Validator::Nested => vec![],
+        }
+    }
+
+    /// Prepends the prefix to the codes of the validator. Codes of errors returned by custom
+    /// functions are only prefixed when overridden.
+    pub fn prefix_code(&mut self, prefix: &str) {
+        match self {
+            Validator::Iter(validators)
+            | Validator::Values(validators)
+            | Validator::Each(validators)
+            | Validator::Warn(validators) => validators
+                .iter_mut()
+                .for_each(|validator| validator.prefix_code(prefix)),
+            Validator::EachEntry { key, value } => key
+                .iter_mut()
+                .chain(value.iter_mut())
+                .for_each(|validator| validator.prefix_code(prefix)),
+            // The codes of errors returned by custom functions are only known when overridden
+            Validator::Custom(v) if v.code.is_some() => v.prefix_code(prefix),
+            Validator::Custom(_) | Validator::Nested => {}
+            Validator::Email(v) => v.prefix_code(prefix),
+            Validator::Url(v) => v.prefix_code(prefix),
+            Validator::ParseNumber(v) => v.prefix_code(prefix),
+            Validator::CreditCard(v) => v.prefix_code(prefix),
+            Validator::Phone(v) => v.prefix_code(prefix),
+            Validator::Range(v) => v.prefix_code(prefix),
+            Validator::Finite(v) => v.prefix_code(prefix),
+            Validator::TryInto(v) => v.prefix_code(prefix),
+            Validator::ValidRange(v) => v.prefix_code(prefix),
+            Validator::RangeLen(v) => v.prefix_code(prefix),
+            Validator::Length(v) => v.prefix_code(prefix),
+            Validator::NonControlCharacter(v) => v.prefix_code(prefix),
+            Validator::Required(v) => v.prefix_code(prefix),
+            Validator::Requires(v) => v.prefix_code(prefix),
+            Validator::MustMatch(v) => v.prefix_code(prefix),
+            Validator::Regex(v) => v.prefix_code(prefix),
+            Validator::EachLine(v) => v.prefix_code(prefix),
+            Validator::Contains(v) => v.prefix_code(prefix),
+            Validator::ContainsWord(v) => v.prefix_code(prefix),
+            Validator::Time(v) => v.prefix_code(prefix),
+            Validator::In(v) => v.prefix_code(prefix),
+            Validator::OneOf(v) => v.prefix_code(prefix),
+            Validator::Ip(v) => v.prefix_code(prefix),
+            Validator::Future(v) => v.prefix_code(prefix),
+            Validator::Past(v) => v.prefix_code(prefix),
+        }
+    }
+}
+
 /// Shortcut for creating simple validation structs.
 ///
 /// ```ignore
@@ -47,7 +207,7 @@ pub enum Validator {
 /// }
 /// ```
 ///
-/// All structs will have a `code` and `message` field
+/// All structs will have a `code`, `message` and `no_code_prefix` field
 macro_rules! validation {
     ($id:ident : $code:literal $(,)? $($der:path),* ; $($key:ident : $typ:ty $(,)?),*) => {
         #[derive(Debug, $($der),*)]
@@ -55,6 +215,7 @@ macro_rules! validation {
             $(pub $key:$typ,)*
             pub code: Option<String>,
             pub message: Option<String>,
+            pub no_code_prefix: bool,
         }
 
         impl $crate::validate::validation::Describe for $id {
@@ -69,6 +230,14 @@ macro_rules! validation {
             fn message(&self) -> Option<&str> {
                 self.message.as_deref()
             }
+
+            fn code_mut(&mut self) -> &mut Option<String> {
+                &mut self.code
+            }
+
+            fn no_code_prefix(&self) -> bool {
+                self.no_code_prefix
+            }
         }
     };
 }
@@ -76,6 +245,7 @@ macro_rules! validation {
 validation!(
     Email : "email",
     Default;
+    require_tld: bool
 );
 
 validation!(
@@ -83,6 +253,12 @@ validation!(
     Default;
 );
 
+validation!(
+    ParseNumber : "parse_number",
+    Default;
+    decimal_separator: char
+);
+
 validation!(
     Phone : "phone",
     Default;
@@ -103,14 +279,89 @@ validation!(
     Default;
     min: Option<ValueOrPath<u64>>,
     max: Option<ValueOrPath<u64>>,
-    equal: Option<ValueOrPath<u64>>
+    equal: Option<ValueOrPath<u64>>,
+    min_from: Option<syn::Path>,
+    max_from: Option<syn::Path>,
+    equal_from: Option<syn::Path>,
+    none_is_zero: bool,
+    ignore_whitespace: bool
 );
 
 validation!(
     Range : "range",
     Default;
-    min: Option<ValueOrPath<f64>>,
-    max: Option<ValueOrPath<f64>>
+    min: Option<RangeBound>,
+    max: Option<RangeBound>,
+    step: Option<ValueOrPath<f64>>,
+    min_message: Option<String>,
+    max_message: Option<String>
+);
+
+/// A `min` or `max` of a range. `Now` is resolved to the current unix timestamp in seconds at
+/// validation time, shifted by the offset. `Char` bounds are compared as chars instead of floats.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RangeBound {
+    Value(f64),
+    Path(syn::Path),
+    Now(i64),
+    Char(char),
+}
+
+impl quote::ToTokens for RangeBound {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            RangeBound::Value(val) => val.to_tokens(tokens),
+            RangeBound::Path(path) => path.to_tokens(tokens),
+            RangeBound::Char(c) => c.to_tokens(tokens),
+            RangeBound::Now(offset) => {
+                tokens.extend(quote::quote!((::validify::__private::unix_now() + #offset)))
+            }
+        }
+    }
+}
+
+impl Range {
+    /// Returns `true` if the range is bounded by chars.
+    pub fn is_char(&self) -> bool {
+        [&self.min, &self.max]
+            .into_iter()
+            .any(|bound| matches!(bound, Some(RangeBound::Char(_))))
+    }
+}
+
+validation!(
+    Finite : "finite",
+    Default;
+);
+
+validation!(
+    TryConvert : "invalid_variant",
+    Default;
+    ty: Option<syn::Type>
+);
+
+validation!(
+    ValidRange : "valid_range",
+    Default;
+);
+
+validation!(
+    RangeLen : "range_len",
+    Default;
+    min: Option<u64>,
+    max: Option<u64>
+);
+
+validation!(
+    Future : "future",
+    Default;
+    grace: i64
+);
+
+validation!(
+    Past : "past",
+    Default;
+    grace: i64
 );
 
 validation!(
@@ -124,50 +375,126 @@ impl MustMatch {
             value: id,
             code: None,
             message: None,
+            no_code_prefix: false,
         }
     }
 }
 
 validation!(
-    Nested : "nested",
+    Required : "required",
     Default;
 );
 
 validation!(
-    Required : "required",
+    Requires : "required",
     Default;
+    fields: Vec<syn::LitStr>,
+    dependents: Vec<(syn::Ident, String)>
 );
 
 validation!(
     Custom : "custom";
-    path: syn::Path
+    path: syn::Path,
+    timeout_ms: Option<u64>,
+    groups: Vec<String>,
+    run_last: bool,
+    by_value: bool
 );
 
 impl Custom {
     pub fn new(f: syn::Path) -> Self {
         Self {
             path: f,
+            timeout_ms: None,
+            groups: vec![],
+            run_last: false,
+            by_value: false,
             code: None,
             message: None,
+            no_code_prefix: false,
         }
     }
 }
 
 validation!(
     Regex : "regex";
-    path: syn::Path
+    regex: ValueOrPath<String>,
+    lowercase_first: bool,
+    trim_first: bool
 );
 
 impl Regex {
-    pub fn new(path: syn::Path) -> Self {
+    pub fn new(regex: ValueOrPath<String>) -> Self {
         Self {
-            path,
+            regex,
+            lowercase_first: false,
+            trim_first: false,
             code: None,
             message: None,
+            no_code_prefix: false,
+        }
+    }
+
+    /// Whether the value is transformed before matching.
+    pub fn transforms(&self) -> bool {
+        self.lowercase_first || self.trim_first
+    }
+
+    /// Returns the literal the field has to match if the pattern is an anchored literal,
+    /// i.e. `^literal$` or `^literal`. These can be checked without compiling a regex.
+    pub fn literal(&self) -> Option<RegexLiteral> {
+        let ValueOrPath::Value(ref pattern) = self.regex else {
+            return None;
+        };
+
+        let pattern = pattern.strip_prefix('^')?;
+
+        let (literal, exact) = match pattern.strip_suffix('$') {
+            Some(literal) => (literal, true),
+            None => (pattern, false),
+        };
+
+        if regex::escape(literal) != literal {
+            return None;
+        }
+
+        if exact {
+            Some(RegexLiteral::Exact(literal.to_string()))
+        } else {
+            Some(RegexLiteral::Prefix(literal.to_string()))
         }
     }
 }
 
+validation!(
+    EachLine : "each_line",
+    Default;
+    regex: Option<Regex>
+);
+
+/// A trivial regex pattern that can be checked with string comparisons.
+#[derive(Debug, PartialEq)]
+pub enum RegexLiteral {
+    /// `^literal$`
+    Exact(String),
+    /// `^literal`
+    Prefix(String),
+}
+
+validation!(
+    ContainsWord : "contains_word",
+    Default;
+    value: Option<ValueOrPath<String>>
+);
+
+validation!(
+    OneOf : "one_of",
+    Default;
+    values: Vec<String>,
+    enum_discriminants: Option<syn::Path>,
+    case_insensitive: bool
+);
+
 validation!(
     Ip : "ip",
     Default;
@@ -186,6 +513,7 @@ pub struct In {
     pub expr: Option<syn::Expr>,
     pub code: Option<String>,
     pub message: Option<String>,
+    pub no_code_prefix: bool,
 }
 
 impl In {
@@ -195,6 +523,7 @@ impl In {
             expr: None,
             code: None,
             message: None,
+            no_code_prefix: false,
         }
     }
 }
@@ -213,6 +542,14 @@ impl Describe for In {
     fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+
+    fn code_mut(&mut self) -> &mut Option<String> {
+        &mut self.code
+    }
+
+    fn no_code_prefix(&self) -> bool {
+        self.no_code_prefix
+    }
 }
 
 #[derive(Debug, Default)]
@@ -221,6 +558,7 @@ pub struct Contains {
     pub value: Option<ValueOrPath<Lit>>,
     pub code: Option<String>,
     pub message: Option<String>,
+    pub no_code_prefix: bool,
 }
 
 impl Describe for Contains {
@@ -237,6 +575,14 @@ impl Describe for Contains {
     fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+
+    fn code_mut(&mut self) -> &mut Option<String> {
+        &mut self.code
+    }
+
+    fn no_code_prefix(&self) -> bool {
+        self.no_code_prefix
+    }
 }
 
 impl Contains {
@@ -258,6 +604,7 @@ pub struct Time {
     pub inclusive: bool,
     pub code: Option<String>,
     pub message: Option<String>,
+    pub no_code_prefix: bool,
 
     /// Used in case a path is used for the duration. We have to keep track of which chrono::Duration method to call.
     pub multiplier: TimeMultiplier,
@@ -266,22 +613,17 @@ pub struct Time {
     pub has_time: bool,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub enum TimeMultiplier {
     Seconds,
     Minutes,
     Hours,
     Days,
     Weeks,
+    #[default]
     None,
 }
 
-impl Default for TimeMultiplier {
-    fn default() -> Self {
-        Self::None
-    }
-}
-
 impl Describe for Time {
     fn code(&self) -> &str {
         if let Some(ref code) = self.code {
@@ -305,6 +647,14 @@ impl Describe for Time {
     fn message(&self) -> Option<&str> {
         self.message.as_deref()
     }
+
+    fn code_mut(&mut self) -> &mut Option<String> {
+        &mut self.code
+    }
+
+    fn no_code_prefix(&self) -> bool {
+        self.no_code_prefix
+    }
 }
 
 impl Time {
@@ -421,7 +771,7 @@ impl Time {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub enum TimeOp {
     BeforeNow,
     AfterNow,
@@ -430,6 +780,7 @@ pub enum TimeOp {
     BeforeFromNow,
     AfterFromNow,
     InPeriod,
+    #[default]
     None,
 }
 
@@ -449,9 +800,3 @@ impl From<String> for TimeOp {
         }
     }
 }
-
-impl Default for TimeOp {
-    fn default() -> Self {
-        Self::None
-    }
-}
@@ -1,29 +1,105 @@
-use super::modifier::Modifier;
+use super::modifier::{HashAlgo, Modifier};
+use crate::builder::quote_builder;
 use crate::tokens::quote_field_modifiers;
+use crate::track_changes::quote_track_changes;
 use crate::{fields::FieldInfo, validate::r#impl::impl_validate};
 use proc_macro_error::abort;
 use quote::quote;
-use syn::parenthesized;
+use syn::{parenthesized, spanned::Spanned};
 
 const TRIM_MODIFIER: &str = "trim";
 const CUSTOM_MODIFIER: &str = "custom";
 const UPPERCASE_MODIFIER: &str = "uppercase";
 const LOWERCASE_MODIFIER: &str = "lowercase";
 const CAPITALIZE_MODIFIER: &str = "capitalize";
+const HASH_MODIFIER: &str = "hash";
+const CLAMP_MODIFIER: &str = "clamp";
+const ENSURE_SCHEME_MODIFIER: &str = "ensure_scheme";
+const PARSE_NUMBER_MODIFIER: &str = "parse_number";
+const MAP_MODIFIER: &str = "map";
+const PAR_MAP_MODIFIER: &str = "par_map";
 const VALIDIFY: &str = "validify";
+const TRACE_MODIFICATIONS: &str = "trace_modifications";
+const BUILDER: &str = "builder";
+const REPAIR: &str = "repair";
+const TRACK_CHANGES: &str = "track_changes";
+const AFTER_VALIDATE: &str = "after_validate";
 const MODIFY: &str = "modify";
+const GROUPS: &str = "groups";
 
 /// Impl entry point
 pub fn impl_validify(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
     let ident = &input.ident;
 
-    let field_info = FieldInfo::collect(input);
+    let mut field_info = FieldInfo::collect(input);
 
-    let (modifiers, nested_validifies) = quote_field_modifiers(field_info);
+    let StructModifiers {
+        functions: struct_modifiers,
+        batches,
+    } = collect_struct_modifiers(&input.attrs, &field_info);
+
+    // Batch modifiers are the same as annotating each of the fields with the custom modifier
+    for batch in batches {
+        for field in batch.fields {
+            let info = field_info
+                .iter_mut()
+                .find(|info| info.name == field.value())
+                .expect("batch fields are checked when parsed");
+            info.modifiers.push(Modifier::Custom {
+                function: batch.function.clone(),
+                control_flow: false,
+            });
+        }
+    }
+
+    let StructValidify {
+        trace_modifications,
+        builder,
+        repair,
+        track_changes,
+        after_validate,
+    } = collect_struct_validify(&input.attrs);
+
+    // The repair function gets a single chance to fix the struct before it is validated again
+    let repair = repair.map(|repair| {
+        quote!(
+            if !errors.is_empty() {
+                #repair(self, &errors);
+                errors = ::validify::ValidationErrors::new();
+                if let Err(errs) = <Self as ::validify::Validate>::validate_groups(self, __groups) {
+                    errors.merge(errs);
+                }
+            }
+        )
+    });
+
+    let builder = builder.then(|| quote_builder(input, &field_info));
+
+    let has_struct_modifiers = !struct_modifiers.is_empty() || !after_validate.is_empty();
+    let track_changes =
+        track_changes.then(|| quote_track_changes(input, &field_info, has_struct_modifiers));
+
+    let (modifiers, nested_validifies, post_validate_modifiers) =
+        quote_field_modifiers(field_info, trace_modifications);
 
     let validate_impl = impl_validate(input);
 
-    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // Struct modifiers receive a snapshot of the struct before modification, so we need `Clone`
+    let mut generics = input.generics.clone();
+    let (snapshot, struct_modifiers) = if struct_modifiers.is_empty() {
+        (quote!(), quote!())
+    } else {
+        generics
+            .make_where_clause()
+            .predicates
+            .push(syn::parse_quote!(Self: ::std::clone::Clone));
+        (
+            quote!(let __original = ::std::clone::Clone::clone(self);),
+            quote!(#(#struct_modifiers(self, &__original);)*),
+        )
+    };
+
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     quote!(
 
@@ -31,29 +107,232 @@ pub fn impl_validify(input: &syn::DeriveInput) -> proc_macro2::TokenStream {
 
     impl #impl_generics ::validify::Modify for #ident #ty_generics #where_clause {
         fn modify(&mut self) {
+            <Self as ::validify::Modify>::modify_groups(self, &[])
+        }
+
+        fn modify_groups(&mut self, __groups: &[&str]) {
+            #snapshot
+
             #(#modifiers)*
+
+            #struct_modifiers
         }
     }
 
     impl #impl_generics ::validify::Validify for #ident #ty_generics #where_clause {
         fn validify(&mut self) -> Result<(), ::validify::ValidationErrors> {
+            <Self as ::validify::Validify>::validify_groups(self, &[])
+        }
+
+        fn validify_groups(&mut self, __groups: &[&str]) -> Result<(), ::validify::ValidationErrors> {
+            <Self as ::validify::Validify>::__validify_unfinished(self, __groups)?;
+            <Self as ::validify::Validify>::__finish_validify(self, __groups);
+            Ok(())
+        }
+
+        fn __validify_unfinished(&mut self, __groups: &[&str]) -> Result<(), ::validify::ValidationErrors> {
             let mut errors = ::validify::ValidationErrors::new();
 
+            #snapshot
+
             #(#nested_validifies)*
 
-            <Self as ::validify::Modify>::modify(self);
+            #(#modifiers)*
+
+            #struct_modifiers
 
-            if let Err(errs) = <Self as ::validify::Validate>::validate(self) {
+            if let Err(errs) = <Self as ::validify::Validate>::validate_groups(self, __groups) {
                 errors.merge(errs);
             }
 
+            #repair
+
             if !errors.is_empty() {
-                Err(errors)
-            } else {
-                Ok(())
+                return Err(errors);
             }
+
+            Ok(())
         }
-    })
+
+        fn __finish_validify(&mut self, __groups: &[&str]) {
+            #(#post_validate_modifiers)*
+
+            #(#after_validate(self);)*
+        }
+    }
+
+    #builder
+
+    #track_changes
+    )
+}
+
+/// Modifiers specified on the struct.
+#[derive(Default)]
+struct StructModifiers {
+    /// `#[modify(custom(function))]`, called with the whole struct and its original.
+    functions: Vec<syn::Path>,
+
+    /// `#[modify(custom(function = "..", fields = [..]))]`, called with each of the fields.
+    batches: Vec<BatchModifier>,
+}
+
+struct BatchModifier {
+    function: syn::Path,
+    fields: Vec<syn::LitStr>,
+}
+
+/// Options specified with `#[validify(..)]` on the struct.
+#[derive(Default)]
+struct StructValidify {
+    /// Whether to emit tracing events for modified fields
+    trace_modifications: bool,
+    /// Whether to generate a builder
+    builder: bool,
+    /// Called with the struct and its errors if validation fails, after which it is validated again
+    repair: Option<syn::Path>,
+    /// Whether to generate `validate_into_tracked`, reporting the fields changed by modifiers
+    track_changes: bool,
+    /// Called with the struct once validation passes when validifying
+    after_validate: Vec<syn::Path>,
+}
+
+/// Collects the `#[validify(..)]` options applied to the struct.
+fn collect_struct_validify(attrs: &[syn::Attribute]) -> StructValidify {
+    let mut options = StructValidify::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(VALIDIFY)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(TRACE_MODIFICATIONS) {
+                options.trace_modifications = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident(BUILDER) {
+                options.builder = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident(TRACK_CHANGES) {
+                options.track_changes = true;
+                return Ok(());
+            }
+
+            if meta.path.is_ident(REPAIR) {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitStr>() else {
+                    return Err(meta.error("repair must be a string literal path to a function"));
+                };
+                options.repair = Some(lit.parse::<syn::Path>()?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(AFTER_VALIDATE) {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitStr>() else {
+                    return Err(
+                        meta.error("after_validate must be a string literal path to a function")
+                    );
+                };
+                options.after_validate.push(lit.parse::<syn::Path>()?);
+                return Ok(());
+            }
+
+            Err(meta.error(
+                "Unrecognized struct validify parameter, accepted are: trace_modifications, builder, repair, track_changes, after_validate",
+            ))
+        })
+        .unwrap_or_else(|e| abort!(e.span(), e));
+    }
+
+    options
+}
+
+/// Collects the `#[modify(custom(..))]` modifiers applied to the struct.
+fn collect_struct_modifiers(attrs: &[syn::Attribute], fields: &[FieldInfo]) -> StructModifiers {
+    let mut modifiers = StructModifiers::default();
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(MODIFY)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(CUSTOM_MODIFIER) {
+                let content;
+                parenthesized!(content in meta.input);
+
+                if content.peek(syn::Ident) && content.peek2(syn::Token![=]) {
+                    modifiers
+                        .batches
+                        .push(parse_batch_modifier(&content, fields)?);
+                } else {
+                    modifiers.functions.push(content.parse::<syn::Path>()?);
+                }
+
+                return Ok(());
+            }
+
+            Err(meta.error("Unrecognized struct modify parameter, accepted are: custom"))
+        })
+        .unwrap_or_else(|e| abort!(e.span(), e));
+    }
+
+    modifiers
+}
+
+/// Parses `function = "..", fields = ["..", ..]`. The fields must exist on the struct.
+fn parse_batch_modifier(
+    content: syn::parse::ParseStream,
+    struct_fields: &[FieldInfo],
+) -> Result<BatchModifier, syn::Error> {
+    let mut function = None;
+    let mut fields = vec![];
+
+    while !content.is_empty() {
+        let key = content.parse::<syn::Ident>()?;
+        content.parse::<syn::Token![=]>()?;
+
+        if key == "function" {
+            let lit = content.parse::<syn::LitStr>()?;
+            function = Some(lit.parse::<syn::Path>()?);
+        } else if key == "fields" {
+            let list;
+            syn::bracketed!(list in content);
+            fields.extend(
+                list.parse_terminated(<syn::LitStr as syn::parse::Parse>::parse, syn::Token![,])?,
+            );
+        } else {
+            return Err(syn::Error::new(
+                key.span(),
+                "Unrecognized custom parameter, accepted are: function, fields",
+            ));
+        }
+
+        if !content.is_empty() {
+            content.parse::<syn::Token![,]>()?;
+        }
+    }
+
+    let Some(function) = function else {
+        return Err(content
+            .error("batch custom modifier must contain a function, i.e. `function = \"..\"`"));
+    };
+
+    if fields.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &function,
+            "batch custom modifier must contain at least one field, i.e. `fields = [\"..\"]`",
+        ));
+    }
+
+    if let Some(field) = fields
+        .iter()
+        .find(|field| !struct_fields.iter().any(|info| info.name == field.value()))
+    {
+        return Err(syn::Error::new(
+            field.span(),
+            format!("Field `{}` does not exist on the struct", field.value()),
+        ));
+    }
+
+    Ok(BatchModifier { function, fields })
 }
 
 pub fn collect_modifiers(field: &syn::Field) -> Vec<Modifier> {
@@ -69,38 +348,409 @@ pub fn collect_modifiers(field: &syn::Field) -> Vec<Modifier> {
             continue;
         }
 
+        let mut attr_modifiers = vec![];
+        let mut groups = vec![];
+
         attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(GROUPS) {
+                groups = parse_groups(&meta)?;
+                return Ok(());
+            }
+
             if meta.path.is_ident(CUSTOM_MODIFIER) {
                 let content;
                 parenthesized!(content in meta.input);
+
+                if content.peek(syn::Ident) && content.peek2(syn::Token![=]) {
+                    attr_modifiers.push(parse_custom_full(&content)?);
+                    return Ok(());
+                }
+
                 let path: syn::Path = content.parse()?;
-                modifiers.push(Modifier::Custom { function: path });
+                attr_modifiers.push(Modifier::Custom {
+                    function: path,
+                    control_flow: false,
+                });
                 return Ok(());
             }
 
             if meta.path.is_ident(TRIM_MODIFIER) {
-                modifiers.push(Modifier::Trim);
+                attr_modifiers.push(Modifier::Trim);
                 return Ok(());
             }
 
             if meta.path.is_ident(LOWERCASE_MODIFIER) {
-                modifiers.push(Modifier::Lowercase);
+                attr_modifiers.push(Modifier::Lowercase);
                 return Ok(());
             }
 
             if meta.path.is_ident(UPPERCASE_MODIFIER) {
-                modifiers.push(Modifier::Uppercase);
+                attr_modifiers.push(Modifier::Uppercase);
                 return Ok(());
             }
 
             if meta.path.is_ident(CAPITALIZE_MODIFIER) {
-                modifiers.push(Modifier::Capitalize);
+                attr_modifiers.push(Modifier::Capitalize);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(HASH_MODIFIER) {
+                attr_modifiers.push(parse_hash(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(CLAMP_MODIFIER) {
+                attr_modifiers.push(parse_clamp(&meta, &field.ty)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(ENSURE_SCHEME_MODIFIER) {
+                attr_modifiers.push(parse_ensure_scheme(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(PARSE_NUMBER_MODIFIER) {
+                attr_modifiers.push(parse_parse_number(&meta)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(MAP_MODIFIER) {
+                attr_modifiers.push(parse_map(&meta, false)?);
+                return Ok(());
+            }
+
+            if meta.path.is_ident(PAR_MAP_MODIFIER) {
+                attr_modifiers.push(parse_map(&meta, true)?);
                 return Ok(());
             }
 
             Err(meta.error("Unrecognized modify parameter"))
         })
         .unwrap_or_else(|e| abort!(e.span(), e));
+
+        if groups.is_empty() {
+            modifiers.extend(attr_modifiers);
+            continue;
+        }
+
+        for modifier in attr_modifiers {
+            if let Modifier::Convert { .. } = modifier {
+                abort!(
+                    attr.span(),
+                    "Modifiers converting from the payload cannot be grouped"
+                )
+            }
+            modifiers.push(Modifier::Grouped {
+                modifier: Box::new(modifier),
+                groups: groups.clone(),
+            });
+        }
     }
     modifiers
 }
+
+/// Parses `groups = ["a", "b"]`.
+fn parse_groups(meta: &syn::meta::ParseNestedMeta) -> Result<Vec<String>, syn::Error> {
+    let content = meta.value()?;
+    let list;
+    syn::bracketed!(list in content);
+    let groups =
+        list.parse_terminated(<syn::LitStr as syn::parse::Parse>::parse, syn::Token![,])?;
+    if groups.is_empty() {
+        return Err(meta.error("groups must contain at least one group"));
+    }
+    Ok(groups.iter().map(syn::LitStr::value).collect())
+}
+
+/// Parses `custom(function = .., from = ..)` and `custom(function = .., control_flow)`. When `from`
+/// is present, the function converts the payload field from the given type.
+fn parse_custom_full(content: syn::parse::ParseStream) -> Result<Modifier, syn::Error> {
+    let mut function = None;
+    let mut from = None;
+    let mut control_flow = false;
+
+    while !content.is_empty() {
+        let key = content.parse::<syn::Ident>()?;
+
+        if key == "control_flow" {
+            control_flow = true;
+            if !content.is_empty() {
+                content.parse::<syn::Token![,]>()?;
+            }
+            continue;
+        }
+
+        content.parse::<syn::Token![=]>()?;
+
+        if key == "function" {
+            function = Some(crate::validate::parser::parse_function_path(content)?);
+        } else if key == "from" {
+            from = Some(Box::new(content.parse::<syn::Type>()?));
+        } else {
+            return Err(syn::Error::new(
+                key.span(),
+                "Unrecognized custom parameter, accepted are: function, from, control_flow",
+            ));
+        }
+
+        if !content.is_empty() {
+            content.parse::<syn::Token![,]>()?;
+        }
+    }
+
+    let Some(function) = function else {
+        return Err(content.error("custom modifier must contain a function"));
+    };
+
+    match from {
+        Some(_) if control_flow => Err(content.error("control_flow cannot be used with from")),
+        Some(from) => Ok(Modifier::Convert { function, from }),
+        None => Ok(Modifier::Custom {
+            function,
+            control_flow,
+        }),
+    }
+}
+
+/// Parses `hash` or `hash(algo = "..", post_validate)`. Defaults to sha256.
+fn parse_hash(meta: &syn::meta::ParseNestedMeta) -> Result<Modifier, syn::Error> {
+    let mut algo = HashAlgo::default();
+    let mut post_validate = false;
+
+    if !meta.input.peek(syn::token::Paren) {
+        return Ok(Modifier::Hash {
+            algo,
+            post_validate,
+        });
+    }
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("algo") {
+            let content = meta.value()?;
+            let Ok(lit) = content.parse::<syn::LitStr>() else {
+                return Err(meta.error("algo must be a string literal"));
+            };
+            algo = match lit.value().as_str() {
+                "sha256" => HashAlgo::Sha256,
+                "sha512" => HashAlgo::Sha512,
+                _ => abort!(
+                    lit.span(),
+                    "Invalid hash algo, accepted are: sha256, sha512"
+                ),
+            };
+            return Ok(());
+        }
+
+        if meta.path.is_ident("post_validate") {
+            post_validate = true;
+            return Ok(());
+        }
+
+        Err(meta.error("Unrecognized hash parameter, accepted are: algo, post_validate"))
+    })?;
+
+    Ok(Modifier::Hash {
+        algo,
+        post_validate,
+    })
+}
+
+/// Parses `map(function = "..")` and `par_map(function = "..")`. The function can also be a path.
+fn parse_map(meta: &syn::meta::ParseNestedMeta, parallel: bool) -> Result<Modifier, syn::Error> {
+    let mut function = None;
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("function") {
+            let content = meta.value()?;
+            function = Some(crate::validate::parser::parse_function_path(content)?);
+            return Ok(());
+        }
+
+        Err(meta.error("Unrecognized map parameter, accepted are: function"))
+    })?;
+
+    let Some(function) = function else {
+        return Err(meta.error("map must contain a function"));
+    };
+
+    Ok(Modifier::Map { function, parallel })
+}
+
+/// Parses `ensure_scheme = "https"`. The scheme must be valid as per RFC 3986.
+fn parse_ensure_scheme(meta: &syn::meta::ParseNestedMeta) -> Result<Modifier, syn::Error> {
+    let content = meta.value()?;
+    let Ok(lit) = content.parse::<syn::LitStr>() else {
+        return Err(
+            meta.error("ensure_scheme must be a string literal, i.e. `ensure_scheme = \"https\"`")
+        );
+    };
+
+    let scheme = lit.value();
+    let mut chars = scheme.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    if !valid {
+        return Err(syn::Error::new(
+            lit.span(),
+            format!("`{scheme}` is not a valid scheme"),
+        ));
+    }
+
+    Ok(Modifier::EnsureScheme(scheme))
+}
+
+/// Parses `parse_number` and `parse_number(decimal_separator = ',')`. The decimal separator is
+/// either `.`, the default, or `,`.
+fn parse_parse_number(meta: &syn::meta::ParseNestedMeta) -> Result<Modifier, syn::Error> {
+    let mut decimal_separator = '.';
+
+    if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|meta| {
+            if meta.path.is_ident("decimal_separator") {
+                let content = meta.value()?;
+                let Ok(lit) = content.parse::<syn::LitChar>() else {
+                    return Err(meta.error("decimal_separator must be a char literal"));
+                };
+                decimal_separator = lit.value();
+                if !matches!(decimal_separator, '.' | ',') {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "decimal_separator must be either '.' or ','",
+                    ));
+                }
+                return Ok(());
+            }
+
+            Err(meta.error("Unrecognized parse_number parameter, accepted are: decimal_separator"))
+        })?;
+    }
+
+    Ok(Modifier::ParseNumber { decimal_separator })
+}
+
+/// Parses `clamp(min = .., max = ..)`. The bounds are literals or paths and at least one is required.
+/// Literal bounds are checked against the field's type, see [coerce_clamp_bound].
+fn parse_clamp(meta: &syn::meta::ParseNestedMeta, ty: &syn::Type) -> Result<Modifier, syn::Error> {
+    let mut min = None;
+    let mut max = None;
+
+    meta.parse_nested_meta(|meta| {
+        if meta.path.is_ident("min") {
+            min = Some(Box::new(meta.value()?.parse::<syn::Expr>()?));
+            return Ok(());
+        }
+
+        if meta.path.is_ident("max") {
+            max = Some(Box::new(meta.value()?.parse::<syn::Expr>()?));
+            return Ok(());
+        }
+
+        Err(meta.error("Unrecognized clamp parameter, accepted are: min, max"))
+    })?;
+
+    if min.is_none() && max.is_none() {
+        return Err(meta.error("clamp must contain a min or max"));
+    }
+
+    let float = innermost_type_ident(ty).and_then(|ident| {
+        if ident == "f32" || ident == "f64" {
+            Some(true)
+        } else {
+            INTEGER_TYPES
+                .iter()
+                .any(|int| ident == int)
+                .then_some(false)
+        }
+    });
+    for bound in min.iter_mut().chain(max.iter_mut()) {
+        coerce_clamp_bound(bound, float)?;
+    }
+
+    // `clamp` panics if min > max, so catch it early when we can
+    if let (Some(lo), Some(hi)) = (
+        min.as_deref().and_then(numeric_literal),
+        max.as_deref().and_then(numeric_literal),
+    ) {
+        if lo > hi {
+            return Err(meta.error("clamp min must not be greater than max"));
+        }
+    }
+
+    Ok(Modifier::Clamp { min, max })
+}
+
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+/// Returns the ident of the innermost type of the field, e.g. `f64` for `Option<Vec<f64>>`.
+fn innermost_type_ident(ty: &syn::Type) -> Option<&syn::Ident> {
+    match ty {
+        syn::Type::Path(path) => {
+            let segment = path.path.segments.last()?;
+            let syn::PathArguments::AngleBracketed(ref args) = segment.arguments else {
+                return Some(&segment.ident);
+            };
+            // The last argument holds the elements, i.e. the values of maps
+            args.args.iter().rev().find_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => innermost_type_ident(ty),
+                _ => None,
+            })
+        }
+        syn::Type::Array(array) => innermost_type_ident(&array.elem),
+        syn::Type::Slice(slice) => innermost_type_ident(&slice.elem),
+        syn::Type::Paren(paren) => innermost_type_ident(&paren.elem),
+        syn::Type::Group(group) => innermost_type_ident(&group.elem),
+        _ => None,
+    }
+}
+
+/// Bounds are not cast to the field's type, so out of range bounds fail to compile instead of
+/// wrapping. Unsuffixed integer literals are written as floats for float fields, `Some(true)`, and
+/// float literals are rejected for integer fields, `Some(false)`. Other bounds are left to the compiler.
+fn coerce_clamp_bound(bound: &mut syn::Expr, float: Option<bool>) -> Result<(), syn::Error> {
+    match bound {
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => coerce_clamp_bound(expr, float),
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => {
+            match (&*lit, float) {
+                (syn::Lit::Int(int), Some(true)) if int.suffix().is_empty() => {
+                    let float = format!("{}.0", int.base10_digits());
+                    *lit = syn::Lit::Float(syn::LitFloat::new(&float, int.span()));
+                }
+                (syn::Lit::Float(float), Some(false)) => {
+                    return Err(syn::Error::new(
+                        float.span(),
+                        "clamp bounds of integer fields must be integers",
+                    ));
+                }
+                _ => {}
+            }
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the value of the expression if it is a possibly negated int or float literal.
+fn numeric_literal(expr: &syn::Expr) -> Option<f64> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(lit),
+            ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => numeric_literal(expr).map(|v| -v),
+        _ => None,
+    }
+}
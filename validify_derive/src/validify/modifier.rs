@@ -7,11 +7,93 @@ pub enum Modifier {
     Uppercase,
     Lowercase,
     Capitalize,
-    Custom { function: syn::Path },
+    /// With `control_flow`, the function returns a `ControlFlow<()>` and breaking skips the
+    /// remaining modifiers on the field.
+    Custom {
+        function: syn::Path,
+        control_flow: bool,
+    },
+    Hash {
+        algo: HashAlgo,
+        post_validate: bool,
+    },
+    /// Numeric bounds the value is clamped to, either literals or paths.
+    Clamp {
+        min: Option<Box<syn::Expr>>,
+        max: Option<Box<syn::Expr>>,
+    },
+    /// Converts the payload field from another type. Only applies when converting from the payload.
+    Convert {
+        function: syn::Path,
+        from: Box<syn::Type>,
+    },
+    /// Prepends the scheme and `://` to strings without a scheme.
+    EnsureScheme(String),
+    /// Strips the grouping separators of numbers and uses `.` for their decimal separator.
+    ParseNumber {
+        decimal_separator: char,
+    },
+    /// Applies the function to each element of the collection, in parallel with `par_map`.
+    Map {
+        function: syn::Path,
+        parallel: bool,
+    },
     Nested,
+    /// Only applied when modifying in any of the groups.
+    Grouped {
+        modifier: Box<Modifier>,
+        groups: Vec<String>,
+    },
+}
+
+/// The digest used by the `hash` modifier.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub enum HashAlgo {
+    #[default]
+    Sha256,
+    Sha512,
 }
 
 impl Modifier {
+    /// Returns `true` if the modifier can stop the remaining modifiers on the field from running.
+    pub fn is_control_flow(&self) -> bool {
+        match self {
+            Modifier::Custom { control_flow, .. } => *control_flow,
+            Modifier::Grouped { modifier, .. } => modifier.is_control_flow(),
+            _ => false,
+        }
+    }
+
+    /// The name of the modifier as it is written in the attribute.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Modifier::Trim => "trim",
+            Modifier::Uppercase => "uppercase",
+            Modifier::Lowercase => "lowercase",
+            Modifier::Capitalize => "capitalize",
+            Modifier::Custom { .. } | Modifier::Convert { .. } => "custom",
+            Modifier::Hash { .. } => "hash",
+            Modifier::Clamp { .. } => "clamp",
+            Modifier::EnsureScheme(_) => "ensure_scheme",
+            Modifier::ParseNumber { .. } => "parse_number",
+            Modifier::Map {
+                parallel: false, ..
+            } => "map",
+            Modifier::Map { parallel: true, .. } => "par_map",
+            Modifier::Nested => "validify",
+            Modifier::Grouped { modifier, .. } => modifier.name(),
+        }
+    }
+
+    /// Returns `true` if the modifier must only be applied once validation passes.
+    pub fn is_post_validate(&self) -> bool {
+        match self {
+            Modifier::Hash { post_validate, .. } => *post_validate,
+            Modifier::Grouped { modifier, .. } => modifier.is_post_validate(),
+            _ => false,
+        }
+    }
+
     /// Returns direct modification tokens as the first element and any nested validify tokens as the second element.
     /// Necessary because we need both in case a nested validify occurs. In that case, the first element will have the
     /// necessary modification tokens for nested elements in the `Modify` impl while the second will have the tokens
@@ -90,30 +172,124 @@ impl Modifier {
                     None,
                 )
             }
-            Modifier::Custom { function } => {
+            Modifier::Hash { algo, .. } => {
+                let hash_fn = algo.hash_fn();
+                let tokens = if field_info.is_option() {
+                    quote!(
+                        *#param = #hash_fn(&#param);
+                    )
+                } else {
+                    quote!(
+                        #param = #hash_fn(&#param);
+                    )
+                };
+                (
+                    field_info.wrap_modifier_if_option(
+                        field_info.wrap_modifier_if_collection(param, tokens, self),
+                    ),
+                    None,
+                )
+            }
+            Modifier::ParseNumber { decimal_separator } => {
+                let tokens = if field_info.is_option() {
+                    quote!(
+                        *#param = ::validify::normalize_number(&#param, #decimal_separator);
+                    )
+                } else {
+                    quote!(
+                        #param = ::validify::normalize_number(&#param, #decimal_separator);
+                    )
+                };
+                (
+                    field_info.wrap_modifier_if_option(
+                        field_info.wrap_modifier_if_collection(param, tokens, self),
+                    ),
+                    None,
+                )
+            }
+            Modifier::EnsureScheme(scheme) => {
                 let tokens = if field_info.is_option() {
                     quote!(
-                        #function(#param);
+                        *#param = ::validify::ensure_scheme(&#param, #scheme);
                     )
                 } else {
                     quote!(
-                        #function(&mut #param);
+                        #param = ::validify::ensure_scheme(&#param, #scheme);
+                    )
+                };
+                (
+                    field_info.wrap_modifier_if_option(
+                        field_info.wrap_modifier_if_collection(param, tokens, self),
+                    ),
+                    None,
+                )
+            }
+            Modifier::Clamp { .. } => {
+                let tokens = if field_info.is_option() {
+                    let clamped = self.quote_clamp(quote!((*#param)));
+                    quote!(
+                        *#param = #clamped;
+                    )
+                } else {
+                    let clamped = self.quote_clamp(quote!(#param));
+                    quote!(
+                        #param = #clamped;
+                    )
+                };
+                (
+                    field_info.wrap_modifier_if_option(
+                        field_info.wrap_modifier_if_collection(param, tokens, self),
+                    ),
+                    None,
+                )
+            }
+            Modifier::Custom {
+                function,
+                control_flow,
+            } => {
+                let call = if field_info.is_option() {
+                    quote!(#function(#param))
+                } else {
+                    quote!(#function(&mut #param))
+                };
+                let tokens = if *control_flow {
+                    quote!(
+                        if ::std::ops::ControlFlow::is_break(&#call) {
+                            break 'modifiers;
+                        }
+                    )
+                } else {
+                    quote!(#call;)
+                };
+                (field_info.wrap_modifier_if_option(tokens), None)
+            }
+            Modifier::Map { function, parallel } => {
+                let tokens = if *parallel {
+                    quote!(::validify::__par_map!(#param, #function);)
+                } else {
+                    quote!(
+                        for el in #param.iter_mut() {
+                            #function(el);
+                        }
                     )
                 };
                 (field_info.wrap_modifier_if_option(tokens), None)
             }
+            Modifier::Convert { .. } => (quote!(), None),
             Modifier::Nested => {
                 let par = param.to_string();
-                let field = par.split('.').last().unwrap();
+                let field = par.split('.').next_back().unwrap();
 
                 let modifications = if field_info.is_list() {
                     quote!(
                         for el in #param.iter_mut() {
-                            el.modify();
+                            ::validify::Modify::modify_groups(el, __groups);
                         }
                     )
+                } else if field_info.is_option() {
+                    quote!(::validify::Modify::modify_groups(#param, __groups);)
                 } else {
-                    quote!(#param.modify();)
+                    quote!(::validify::Modify::modify_groups(&mut #param, __groups);)
                 };
 
                 let field_ident: proc_macro2::TokenStream =
@@ -129,15 +305,20 @@ impl Modifier {
                 let nested_validifies = if field_info.is_list() {
                     quote!(
                         for (i, el) in #param.iter_mut().enumerate() {
-                            if let Err(mut errs) = el.validify() {
+                            if let Err(mut errs) = ::validify::Validify::__validify_unfinished(el, __groups) {
                                 errs.errors_mut().iter_mut().for_each(|err|err.set_location_idx(i, #field));
                                 errors.merge(errs);
                             }
                         }
                     )
                 } else {
+                    let param = if field_info.is_option() {
+                        quote!(#param)
+                    } else {
+                        quote!(&mut #param)
+                    };
                     quote!(
-                        if let Err(mut err) = #param.validify() {
+                        if let Err(mut err) = ::validify::Validify::__validify_unfinished(#param, __groups) {
                             err.errors_mut().iter_mut().for_each(|e| e.set_location(#field));
                             errors.merge(err);
                         }
@@ -148,6 +329,66 @@ impl Modifier {
                     Some(field_info.wrap_modifier_if_option(nested_validifies)),
                 )
             }
+            Modifier::Grouped { modifier, groups } => {
+                let (tokens, nested) = modifier.to_validify_tokens(field_info);
+                let in_groups = quote!([#(#groups),*].iter().any(|group| __groups.contains(group)));
+                (
+                    quote!(if #in_groups { #tokens }),
+                    nested.map(|nested| quote!(if #in_groups { #nested })),
+                )
+            }
+        }
+    }
+
+    /// Returns the tokens finishing a nested validify once the outermost struct is valid, running
+    /// its `post_validate` modifiers and `after_validate` functions.
+    pub fn quote_nested_finish(&self, field_info: &FieldInfo) -> Option<proc_macro2::TokenStream> {
+        match self {
+            Modifier::Nested => {
+                let param = field_info.quote_modifier_param();
+                let tokens = if field_info.is_list() {
+                    quote!(
+                        for el in #param.iter_mut() {
+                            ::validify::Validify::__finish_validify(el, __groups);
+                        }
+                    )
+                } else if field_info.is_option() {
+                    quote!(::validify::Validify::__finish_validify(#param, __groups);)
+                } else {
+                    quote!(::validify::Validify::__finish_validify(&mut #param, __groups);)
+                };
+                Some(field_info.wrap_modifier_if_option(tokens))
+            }
+            Modifier::Grouped { modifier, groups } => {
+                let tokens = modifier.quote_nested_finish(field_info)?;
+                Some(
+                    quote!(if [#(#groups),*].iter().any(|group| __groups.contains(group)) { #tokens }),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    /// Quotes the clamped value. Uses `clamp`, `max` and `min` so both integers and floats are supported.
+    pub fn quote_clamp(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Modifier::Clamp { min, max } = self else {
+            unreachable!("not a clamp modifier")
+        };
+        match (min, max) {
+            (Some(min), Some(max)) => quote!(#value.clamp(#min, #max)),
+            (Some(min), None) => quote!(#value.max(#min)),
+            (None, Some(max)) => quote!(#value.min(#max)),
+            (None, None) => unreachable!("clamp always has a bound"),
+        }
+    }
+}
+
+impl HashAlgo {
+    /// Returns the path to the runtime function computing the digest.
+    pub fn hash_fn(&self) -> proc_macro2::TokenStream {
+        match self {
+            HashAlgo::Sha256 => quote!(::validify::hash_sha256),
+            HashAlgo::Sha512 => quote!(::validify::hash_sha512),
         }
     }
 }
@@ -0,0 +1,63 @@
+impl ::validify::Validate for Booking {
+    fn validate(&self) -> ::std::result::Result<(), ::validify::ValidationErrors> {
+        <Self as ::validify::Validate>::validate_groups(self, &[])
+    }
+    fn validate_groups(
+        &self,
+        __groups: &[&str],
+    ) -> ::std::result::Result<(), ::validify::ValidationErrors> {
+        let mut errors = ::validify::ValidationErrors::new();
+        if !::validify::validate_required(&self.room_id) {
+            let mut err = ::validify::ValidationError::new_field("booking.required");
+            err.set_field("roomId");
+            err.set_location("roomId");
+            errors.add(err);
+        }
+        if !(*&self.start_at as f64).is_finite()
+            || !::validify::validate_range(
+                *&self.start_at as f64,
+                Some((::validify::__private::unix_now() + 0i64) as f64),
+                None,
+            )
+        {
+            let mut err = ::validify::ValidationError::new_field("booking.range");
+            err.set_field("startAt");
+            err.add_param("min", &(::validify::__private::unix_now() + 0i64));
+            err.add_param("actual", &&self.start_at);
+            err.set_location("startAt");
+            errors.add(err);
+        }
+        if !(*&self.end_at as f64).is_finite()
+            || !::validify::validate_range(
+                *&self.end_at as f64,
+                Some((::validify::__private::unix_now() + -3600i64) as f64),
+                None,
+            )
+        {
+            let mut err = ::validify::ValidationError::new_field("range");
+            err.set_field("endAt");
+            err.add_param("min", &(::validify::__private::unix_now() + -3600i64));
+            err.add_param("actual", &&self.end_at);
+            err.set_location("endAt");
+            errors.add(err);
+        }
+        if let Err(mut errs) = validate_booking(&self) {
+            errors.merge(errs);
+        }
+        if !starts_before_end(self) {
+            errors
+                .add(
+                    ::validify::ValidationError::new_schema("predicate")
+                        .with_message(
+                            ::std::string::String::from("start must be before end"),
+                        ),
+                );
+        }
+        errors.dedup();
+        if errors.is_empty() {
+            ::std::result::Result::Ok(())
+        } else {
+            ::std::result::Result::Err(errors)
+        }
+    }
+}
@@ -0,0 +1,12 @@
+#[derive(Validate)]
+#[validate(validate_booking, code_prefix = "booking.", dedup_errors)]
+#[validate(predicate(function = "starts_before_end", message = "start must be before end"))]
+#[serde(rename_all = "camelCase")]
+struct Booking {
+    #[validate(required)]
+    room_id: Option<u64>,
+    #[validate(range(min = "now"))]
+    start_at: i64,
+    #[validate(range(min = "now-3600", no_code_prefix))]
+    end_at: i64,
+}
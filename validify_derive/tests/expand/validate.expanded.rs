@@ -0,0 +1,116 @@
+impl ::validify::Validate for Signup {
+    fn validate(&self) -> ::std::result::Result<(), ::validify::ValidationErrors> {
+        <Self as ::validify::Validate>::validate_groups(self, &[])
+    }
+    fn validate_groups(
+        &self,
+        __groups: &[&str],
+    ) -> ::std::result::Result<(), ::validify::ValidationErrors> {
+        let mut errors = ::validify::ValidationErrors::new();
+        if !::validify::validate_email(&self.email) {
+            let mut err = ::validify::ValidationError::new_field("email");
+            err.set_field("email");
+            err.add_param("actual", &&self.email);
+            err.set_location("email");
+            errors.add(err);
+        }
+        {
+            let __length_min = 1u64 as u64;
+            let __length_max = 50u64 as u64;
+            if !::validify::validate_length(
+                {
+                    use ::validify::__private::{SelectHasLen as _, SelectStrLen as _};
+                    (&::validify::__private::Select(&self.username)).has_len()
+                },
+                Some(__length_min),
+                Some(__length_max),
+                None,
+            ) {
+                let mut err = ::validify::ValidationError::new_field("length");
+                err.set_field("username");
+                err.add_param("min", &__length_min);
+                err.add_param("max", &__length_max);
+                err.add_param(
+                    "actual",
+                    &::validify::traits::HasLen::length(
+                        &{
+                            use ::validify::__private::{
+                                SelectHasLen as _, SelectStrLen as _,
+                            };
+                            (&::validify::__private::Select(&self.username)).has_len()
+                        },
+                    ),
+                );
+                err.set_location("username");
+                errors.add(err);
+            }
+        }
+        if !(*&self.age as f64).is_finite()
+            || !::validify::validate_range(
+                *&self.age as f64,
+                Some(18f64 as f64),
+                Some(150f64 as f64),
+            )
+        {
+            let mut err = ::validify::ValidationError::new_field("range");
+            err.set_field("age");
+            err.add_param("min", &18f64);
+            err.add_param("max", &150f64);
+            err.add_param("actual", &&self.age);
+            err.set_location("age");
+            errors.add(err);
+        }
+        for (__i, el) in self.tags.iter().enumerate() {
+            {
+                let __length_max = 20u64 as u64;
+                if !::validify::validate_length(
+                    {
+                        use ::validify::__private::{
+                            SelectHasLen as _, SelectStrLen as _,
+                        };
+                        (&::validify::__private::Select(el)).has_len()
+                    },
+                    None,
+                    Some(__length_max),
+                    None,
+                ) {
+                    let mut err = ::validify::ValidationError::new_field("length");
+                    err.set_field("tags");
+                    err.add_param("max", &__length_max);
+                    err.add_param(
+                        "actual",
+                        &::validify::traits::HasLen::length(
+                            &{
+                                use ::validify::__private::{
+                                    SelectHasLen as _, SelectStrLen as _,
+                                };
+                                (&::validify::__private::Select(el)).has_len()
+                            },
+                        ),
+                    );
+                    err.set_location_idx(__i, "tags");
+                    errors.add(err);
+                }
+            }
+        }
+        if let Some(ref referrer) = self.referrer {
+            if let Err(mut err) = check_referrer(referrer)
+                .map_err(::std::convert::Into::<::validify::ValidationError>::into)
+            {
+                let f_name = err.field_name().map(|s| s.to_string());
+                if let Some(field_name) = f_name {
+                    err.set_location(field_name);
+                } else {
+                    err.set_field("referrer");
+                    err.set_location("referrer");
+                }
+                errors.add(err);
+            }
+        }
+        if errors.is_empty() {
+            ::std::result::Result::Ok(())
+        } else {
+            ::std::result::Result::Err(errors)
+        }
+    }
+}
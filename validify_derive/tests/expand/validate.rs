@@ -0,0 +1,13 @@
+#[derive(Validate)]
+struct Signup {
+    #[validate(email)]
+    email: String,
+    #[validate(length(min = 1, max = 50))]
+    username: String,
+    #[validate(range(min = 18., max = 150.))]
+    age: u8,
+    #[validate(iter(length(max = 20)))]
+    tags: Vec<String>,
+    #[validate(custom(check_referrer))]
+    referrer: Option<String>,
+}
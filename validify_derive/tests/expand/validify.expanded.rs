@@ -0,0 +1,134 @@
+impl ::validify::Validate for Profile {
+    fn validate(&self) -> ::std::result::Result<(), ::validify::ValidationErrors> {
+        <Self as ::validify::Validate>::validate_groups(self, &[])
+    }
+    fn validate_groups(
+        &self,
+        __groups: &[&str],
+    ) -> ::std::result::Result<(), ::validify::ValidationErrors> {
+        let mut errors = ::validify::ValidationErrors::new();
+        if !::validify::validate_email(&self.email) {
+            let mut err = ::validify::ValidationError::new_field("email");
+            err.set_field("email");
+            err.add_param("actual", &&self.email);
+            err.set_location("email");
+            errors.add(err);
+        }
+        if let Some(ref name) = self.name {
+            {
+                let __length_min = 1u64 as u64;
+                if !::validify::validate_length(
+                    {
+                        use ::validify::__private::{
+                            SelectHasLen as _, SelectStrLen as _,
+                        };
+                        (&::validify::__private::Select(name)).has_len()
+                    },
+                    Some(__length_min),
+                    None,
+                    None,
+                ) {
+                    let mut err = ::validify::ValidationError::new_field("length");
+                    err.set_field("name");
+                    err.add_param("min", &__length_min);
+                    err.add_param(
+                        "actual",
+                        &::validify::traits::HasLen::length(
+                            &{
+                                use ::validify::__private::{
+                                    SelectHasLen as _, SelectStrLen as _,
+                                };
+                                (&::validify::__private::Select(name)).has_len()
+                            },
+                        ),
+                    );
+                    err.set_location("name");
+                    errors.add(err);
+                }
+            }
+        }
+        if let Err(mut errs) = self.address.validate_groups(__groups) {
+            errs.errors_mut().iter_mut().for_each(|err| err.set_location("address"));
+            errors.merge(errs);
+        }
+        if errors.is_empty() {
+            ::std::result::Result::Ok(())
+        } else {
+            ::std::result::Result::Err(errors)
+        }
+    }
+    fn validate_warnings(&self) -> ::validify::ValidationErrors {
+        let __groups: &[&str] = &[];
+        let mut errors = ::validify::ValidationErrors::new();
+        let mut warnings = self.address.validate_warnings();
+        warnings.errors_mut().iter_mut().for_each(|err| err.set_location("address"));
+        errors.merge(warnings);
+        errors
+    }
+}
+impl ::validify::Modify for Profile {
+    fn modify(&mut self) {
+        <Self as ::validify::Modify>::modify_groups(self, &[])
+    }
+    fn modify_groups(&mut self, __groups: &[&str]) {
+        self.email = self.email.trim().to_string();
+        self.email = self.email.to_lowercase();
+        if let Some(name) = self.name.as_mut() {
+            *name = name.trim().to_string();
+        }
+        if let Some(name) = self.name.as_mut() {
+            *name = ::std::format!("{}{}", & name[0..1].to_uppercase(), & name[1..]);
+        }
+        normalize_bio(&mut self.bio);
+        ::validify::Modify::modify_groups(&mut self.address, __groups);
+    }
+}
+impl ::validify::Validify for Profile {
+    fn validify(&mut self) -> Result<(), ::validify::ValidationErrors> {
+        <Self as ::validify::Validify>::validify_groups(self, &[])
+    }
+    fn validify_groups(
+        &mut self,
+        __groups: &[&str],
+    ) -> Result<(), ::validify::ValidationErrors> {
+        <Self as ::validify::Validify>::__validify_unfinished(self, __groups)?;
+        <Self as ::validify::Validify>::__finish_validify(self, __groups);
+        Ok(())
+    }
+    fn __validify_unfinished(
+        &mut self,
+        __groups: &[&str],
+    ) -> Result<(), ::validify::ValidationErrors> {
+        let mut errors = ::validify::ValidationErrors::new();
+        if let Err(mut err) = ::validify::Validify::__validify_unfinished(
+            &mut self.address,
+            __groups,
+        ) {
+            err.errors_mut().iter_mut().for_each(|e| e.set_location(" address"));
+            errors.merge(err);
+        }
+        self.email = self.email.trim().to_string();
+        self.email = self.email.to_lowercase();
+        if let Some(name) = self.name.as_mut() {
+            *name = name.trim().to_string();
+        }
+        if let Some(name) = self.name.as_mut() {
+            *name = ::std::format!("{}{}", & name[0..1].to_uppercase(), & name[1..]);
+        }
+        normalize_bio(&mut self.bio);
+        ::validify::Modify::modify_groups(&mut self.address, __groups);
+        if let Err(errs) = <Self as ::validify::Validate>::validate_groups(
+            self,
+            __groups,
+        ) {
+            errors.merge(errs);
+        }
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+        Ok(())
+    }
+    fn __finish_validify(&mut self, __groups: &[&str]) {
+        ::validify::Validify::__finish_validify(&mut self.address, __groups);
+    }
+}
@@ -0,0 +1,13 @@
+#[derive(Validify)]
+struct Profile {
+    #[modify(trim, lowercase)]
+    #[validate(email)]
+    email: String,
+    #[modify(trim, capitalize)]
+    #[validate(length(min = 1))]
+    name: Option<String>,
+    #[modify(custom(normalize_bio))]
+    bio: String,
+    #[validify]
+    address: Address,
+}